@@ -1,35 +1,77 @@
-use criterion::{criterion_group, criterion_main, Criterion};
-use number_renderer::NumbersToWords;
+use std::fmt::Write;
 
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use number_renderer::{NumbersToWords, WriteWords};
+
+const PYRAMID_OF_NINES: [i64; 14] = [
+    9,
+    99,
+    999,
+    9_999,
+    99_999,
+    999_999,
+    9_999_999,
+    99_999_999,
+    999_999_999,
+    9_999_999_999,
+    99_999_999_999,
+    999_999_999_999,
+    99_999_999_999_999,
+    999_999_999_999_999
+];
 
 fn criterion_benchmark(c: &mut Criterion) {
-    c.bench_function("display all the nines", |b| {
+    let mut group = c.benchmark_group("all the nines");
+
+    group.bench_function("display", |b| {
+        b.iter(|| {
+            PYRAMID_OF_NINES
+                .iter()
+                .for_each(|n| {
+                    black_box(format!("{}", NumbersToWords::new(n.to_owned())));
+                })
+        })
+    });
+
+    group.bench_function("write into a reused string", |b| {
+        let mut out = String::with_capacity(256);
+        b.iter(|| {
+            PYRAMID_OF_NINES
+                .iter()
+                .for_each(|n| {
+                    out.clear();
+                    NumbersToWords::new(n.to_owned()).write_to(&mut out).unwrap();
+                    black_box(&out);
+                })
+        })
+    });
+
+    group.bench_function("display into a reused string", |b| {
+        let mut out = String::with_capacity(256);
+        b.iter(|| {
+            PYRAMID_OF_NINES
+                .iter()
+                .for_each(|n| {
+                    out.clear();
+                    write!(out, "{}", NumbersToWords::new(n.to_owned())).unwrap();
+                    black_box(&out);
+                })
+        })
+    });
+
+    group.bench_function("write into a buffer", |b| {
+        let mut buffer = [0_u8; 256];
         b.iter(|| {
-            let pyramid_of_nines = vec![
-                9,
-                99,
-                999,
-                9_999,
-                99_999,
-                9_99_999,
-                99_999_99,
-                999_999_99,
-                9_999_999_99,
-                99_999_999_99,
-                999_999_999_99,
-                9_999_999_999_99,
-                99_999_999_999_999,
-                999_999_999_999_999
-            ];
-
-            pyramid_of_nines   
+            PYRAMID_OF_NINES
                 .iter()
                 .for_each(|n| {
-                    format!("{}", NumbersToWords::new(n.to_owned()));
+                    black_box(NumbersToWords::new(n.to_owned()).write_to_buffer(&mut buffer).unwrap());
                 })
         })
     });
+
+    group.finish();
 }
 
 criterion_group!(benches, criterion_benchmark);
-criterion_main!(benches);
\ No newline at end of file
+criterion_main!(benches);
@@ -0,0 +1,296 @@
+use alloc::{format, string::String};
+use core::fmt::Display;
+
+use crate::{
+    decimal::Decimal,
+    numbers_to_words::{NumbersToWords, Render},
+    scales::{long_scale_name, scale_name},
+};
+
+// Suffixes for the compact form, up to quadrillions
+const SUFFIXES: [&str; 6] = ["", "K", "M", "B", "T", "Q"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Rounding {
+    // To the closest value, with halves rounded up, read as "about"
+    #[default]
+    Nearest,
+    // Towards zero, read as "over", or "under" for negatives
+    Down,
+    // Away from zero, read as "nearly"
+    Up,
+}
+
+// A number rounded to a few significant figures, "about one point two million" as
+// words or "1.2M" on its own
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Approximate<N> {
+    pub(crate) number: N,
+    significant: u32,
+    rounding: Rounding,
+}
+
+impl<N> Approximate<N> {
+    pub fn new(number: N) -> Self {
+        Approximate {
+            number,
+            significant: 2,
+            rounding: Rounding::Nearest,
+        }
+    }
+
+    // At least one figure is always kept
+    pub fn significant(mut self, figures: u32) -> Self {
+        self.significant = figures.max(1);
+        self
+    }
+
+    // Rounding applies to the size of the number, so down is towards zero for negatives too.
+    // Where rounding up would pass the largest u128 the value is rounded down instead, and
+    // read as "over"
+    pub fn rounding(mut self, rounding: Rounding) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    // The magnitude rounded to the significant figures, and how the exact value compares with it
+    fn round(&self, negative: bool, magnitude: u128) -> (u128, Option<&'static str>) {
+        let digits = magnitude.checked_ilog10().map_or(1, |log| log + 1);
+        if digits <= self.significant {
+            return (magnitude, None);
+        }
+
+        let unit = 10_u128.pow(digits - self.significant);
+        let (kept, dropped) = (magnitude / unit, magnitude % unit);
+        let up = match self.rounding {
+            Rounding::Nearest => dropped >= unit - dropped,
+            Rounding::Down => false,
+            Rounding::Up => dropped > 0,
+        };
+
+        // Rounding up past the largest u128 keeps the value rounded down
+        let rounded = match up {
+            true => (kept + 1).checked_mul(unit).unwrap_or(kept * unit),
+            false => kept * unit,
+        };
+
+        // A negative rounded towards zero is above its value, -5999 is under minus five thousand
+        let qualifier = match (rounded == magnitude, self.rounding, rounded < magnitude, negative) {
+            (true, _, _, _) => None,
+            (false, Rounding::Nearest, _, _) => Some("about"),
+            (false, _, true, false) => Some("over"),
+            (false, _, true, true) => Some("under"),
+            (false, _, false, _) => Some("nearly"),
+        };
+
+        (rounded, qualifier)
+    }
+}
+
+// The scale a value is read in, as a power of a thousand, at most the given one
+fn scale(value: u128, largest: usize) -> usize {
+    let digits = value.checked_ilog10().unwrap_or(0) as usize;
+    (digits / 3).min(largest)
+}
+
+// The value in units of 1000^scale, "1.2" for 1,200,000 in millions
+fn mantissa(value: u128, scale: usize) -> String {
+    let places = scale * 3;
+    let unit = 10_u128.pow(places as u32);
+    let fraction = format!("{:0>places$}", value % unit);
+    let fraction = fraction.trim_end_matches('0');
+
+    match fraction.is_empty() {
+        true => format!("{}", value / unit),
+        false => format!("{}.{}", value / unit, fraction),
+    }
+}
+
+impl<N> NumbersToWords<Approximate<N>> {
+    fn write_approximate(&self, f: &mut core::fmt::Formatter<'_>, negative: bool, magnitude: u128) -> core::fmt::Result {
+        let (rounded, qualifier) = self.number.round(negative, magnitude);
+        if let Some(qualifier) = qualifier {
+            write!(f, "{} ", qualifier)?;
+        }
+
+        if negative && rounded > 0 {
+            write!(f, "{} ", self.negative())?;
+        }
+
+        let name = |scale: usize| match self.settings.options.long_scale {
+            true => long_scale_name(scale),
+            false => scale_name(scale),
+        };
+
+        // Half of the next scale up reads better than five hundred thousand
+        let scale = scale(rounded, usize::MAX);
+        let next = 10_u128.checked_pow(scale as u32 * 3 + 3);
+        if scale >= 1 && next.is_some_and(|next| rounded.checked_mul(2) == Some(next)) {
+            return write!(f, "half a {}", name(scale + 1));
+        }
+
+        let mantissa = mantissa(rounded, scale).parse::<Decimal>().map_err(|_| core::fmt::Error)?;
+        write!(f, "{}", self.with_number(mantissa).with_ordinal(false))?;
+        if scale > 0 {
+            write!(f, " {}", name(scale))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<N> Approximate<N> {
+    fn write_compact(&self, f: &mut core::fmt::Formatter<'_>, negative: bool, magnitude: u128) -> core::fmt::Result {
+        let (rounded, _) = self.round(negative, magnitude);
+        if negative && rounded > 0 {
+            write!(f, "-")?;
+        }
+
+        let scale = scale(rounded, SUFFIXES.len() - 1);
+        write!(f, "{}{}", mantissa(rounded, scale), SUFFIXES[scale])
+    }
+}
+
+macro_rules! approximate_unsigned {
+    ($($t:ty),*) => {
+        $(
+            impl Display for Approximate<$t> {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    self.write_compact(f, false, self.number as u128)
+                }
+            }
+
+            impl Render for NumbersToWords<Approximate<$t>> {
+                fn render(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    self.write_approximate(f, false, self.number.number as u128)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! approximate_signed {
+    ($($t:ty),*) => {
+        $(
+            impl Display for Approximate<$t> {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    self.write_compact(f, self.number < 0, self.number.unsigned_abs() as u128)
+                }
+            }
+
+            impl Render for NumbersToWords<Approximate<$t>> {
+                fn render(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    let number = self.number.number;
+                    self.write_approximate(f, number < 0, number.unsigned_abs() as u128)
+                }
+            }
+        )*
+    };
+}
+
+approximate_unsigned!(u8, u16, u32, u64, u128, usize);
+approximate_signed!(i8, i16, i32, i64, i128, isize);
+
+#[cfg(test)]
+mod tests {
+    use alloc::{format, string::String};
+
+    use super::{Approximate, Rounding};
+    use crate::{numbers_to_words::NumbersToWords, render_options::RenderOptions};
+
+    fn words(approximate: Approximate<i64>) -> String {
+        format!("{}", NumbersToWords::new(approximate))
+    }
+
+    #[test]
+    fn displays_approximate_words() {
+        assert_eq!(words(Approximate::new(1_234_567)), "about one point two million");
+        assert_eq!(words(Approximate::new(1_200_000)), "one point two million");
+        assert_eq!(words(Approximate::new(3_000_000_000)), "three billion");
+        assert_eq!(words(Approximate::new(987)), "about nine hundred and ninety");
+        assert_eq!(words(Approximate::new(42)), "forty two");
+        assert_eq!(words(Approximate::new(0)), "zero");
+        assert_eq!(words(Approximate::new(45_600)), "about forty six thousand");
+        assert_eq!(words(Approximate::new(-1_250_000)), "about minus one point three million");
+        assert_eq!(words(Approximate::new(999_950)), "about one million");
+        assert_eq!(words(Approximate::new(7_123_000_000_000_000)), "about seven point one quadrillion");
+    }
+
+    #[test]
+    fn displays_rounding_qualifiers() {
+        let up = |number| words(Approximate::new(number).significant(1).rounding(Rounding::Up));
+        let down = |number| words(Approximate::new(number).significant(1).rounding(Rounding::Down));
+
+        assert_eq!(up(2_950_000_000), "nearly three billion");
+        assert_eq!(up(3_000_000_000), "three billion");
+        assert_eq!(down(512_000), "over half a million");
+        assert_eq!(down(5_999), "over five thousand");
+        assert_eq!(down(-5_999), "under minus five thousand");
+        assert_eq!(up(-2_950), "nearly minus three thousand");
+        assert_eq!(down(-5_000), "minus five thousand");
+    }
+
+    #[test]
+    fn displays_halves() {
+        assert_eq!(words(Approximate::new(500_000)), "half a million");
+        assert_eq!(words(Approximate::new(500_000_000_000)), "half a trillion");
+        assert_eq!(words(Approximate::new(500)), "five hundred");
+        assert_eq!(words(Approximate::new(5_000)), "five thousand");
+    }
+
+    #[test]
+    fn displays_significant_figures() {
+        let figures = |figures| words(Approximate::new(1_234_567).significant(figures));
+
+        assert_eq!(figures(0), "about one million");
+        assert_eq!(figures(1), "about one million");
+        assert_eq!(figures(3), "about one point two three million");
+        assert_eq!(figures(4), "about one point two three five million");
+        assert_eq!(figures(7), "one point two three four five six seven million");
+        assert_eq!(figures(20), "one point two three four five six seven million");
+    }
+
+    #[test]
+    fn displays_with_options() {
+        let actual = NumbersToWords::new(Approximate::new(2_500_000_000_i64)).options(RenderOptions { long_scale: true, ..RenderOptions::default() });
+        assert_eq!(format!("{actual}"), "two point five milliard");
+
+        let actual = NumbersToWords::new(Approximate::new(50_000_000_000_i64)).options(RenderOptions { long_scale: true, ..RenderOptions::default() });
+        assert_eq!(format!("{actual}"), "fifty milliard");
+    }
+
+    #[test]
+    fn displays_compact() {
+        let compact = |number: i64| format!("{}", Approximate::new(number));
+
+        assert_eq!(compact(1_234_567), "1.2M");
+        assert_eq!(compact(999), "1K");
+        assert_eq!(compact(987), "990");
+        assert_eq!(compact(12_345), "12K");
+        assert_eq!(compact(-3_400_000_000), "-3.4B");
+        assert_eq!(compact(1_500_000_000_000), "1.5T");
+        assert_eq!(compact(2_000_000_000_000_000), "2Q");
+        assert_eq!(compact(0), "0");
+        assert_eq!(compact(-4), "-4");
+        assert_eq!(format!("{}", Approximate::new(i64::MAX).significant(3)), "9220Q");
+        assert_eq!(format!("{}", Approximate::new(1_999_u16).rounding(Rounding::Down)), "1.9K");
+    }
+
+    #[test]
+    fn displays_type_extremes() {
+        assert_eq!(format!("{}", NumbersToWords::new(Approximate::new(u128::MAX))), "about three hundred and forty undecillion");
+        assert_eq!(format!("{}", NumbersToWords::new(Approximate::new(i8::MIN))), "about minus one hundred and thirty");
+    }
+
+    #[test]
+    fn rounds_down_when_up_overflows() {
+        let up = |figures| Approximate::new(u128::MAX).significant(figures).rounding(Rounding::Up);
+
+        assert_eq!(format!("{}", NumbersToWords::new(up(2))), "over three hundred and forty undecillion");
+        assert_eq!(format!("{}", NumbersToWords::new(up(1))), "over three hundred undecillion");
+        assert_eq!(format!("{}", up(1)), "300000000000000000000000Q");
+
+        let actual = Approximate::new(i128::MIN).significant(1).rounding(Rounding::Up);
+        assert_eq!(format!("{}", NumbersToWords::new(actual)), "nearly minus two hundred undecillion");
+    }
+}
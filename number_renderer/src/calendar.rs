@@ -0,0 +1,321 @@
+use crate::numbers_to_words::{NumbersToWords, Render};
+
+const MONTHS: [&str; 12] = [
+    "January", "February", "March", "April", "May", "June", "July", "August", "September", "October",
+    "November", "December",
+];
+
+// February allows the 29th, as without the year a leap day cannot be ruled out
+const DAYS_IN_MONTH: [u8; 12] = [31, 29, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TimeStyle {
+    // "quarter past three", "ten to midnight"
+    Spoken,
+    // "three fifteen", "three oh five"
+    Digital,
+    // "fifteen fifteen hours", "zero nine hundred hours"
+    Military,
+}
+
+// A year read the way people say it, "nineteen eighty four" rather than "one thousand nine hundred..."
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Year(pub i32);
+
+// A time of day on the 24 hour clock
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Time {
+    pub(crate) hour: u8,
+    pub(crate) minute: u8,
+}
+
+impl Time {
+    // None when the hour or minute is past the end of the clock
+    pub fn new(hour: u8, minute: u8) -> Option<Self> {
+        (hour < 24 && minute < 60).then_some(Time { hour, minute })
+    }
+}
+
+// A day of the month, read as "the twenty first of March", with the year after it when known
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Date {
+    pub(crate) year: Option<Year>,
+    pub(crate) month: u8,
+    pub(crate) day: u8,
+}
+
+impl Date {
+    // Months count from 1, None when the month has no such day
+    pub fn new(day: u8, month: u8) -> Option<Self> {
+        let days = *DAYS_IN_MONTH.get((month as usize).checked_sub(1)?)?;
+        (1..=days).contains(&day).then_some(Date { year: None, month, day })
+    }
+
+    // None on the 29th of February outside a leap year
+    pub fn year(self, year: i32) -> Option<Self> {
+        let leap = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+        if self.month == 2 && self.day == 29 && !leap {
+            return None;
+        }
+
+        Some(Date {
+            year: Some(Year(year)),
+            ..self
+        })
+    }
+}
+
+impl<N> NumbersToWords<N> {
+    // A number said on its own, never as an ordinal
+    fn said(&self, number: u32) -> NumbersToWords<u128> {
+        self.with_number(number as u128).with_ordinal(false)
+    }
+}
+
+impl Render for NumbersToWords<Year> {
+    fn render(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let year = self.number.0.unsigned_abs();
+        let (century, rest) = (year / 100, year % 100);
+        match year {
+            // Early and far future years, and the first years of a millennium, are read in full
+            _ if !(1000..10_000).contains(&year) || year % 1000 < 10 => write!(f, "{}", self.said(year))?,
+            _ if rest == 0 => write!(f, "{} hundred", self.said(century))?,
+            _ if rest < 10 => write!(f, "{} oh {}", self.said(century), self.said(rest))?,
+            _ => write!(f, "{} {}", self.said(century), self.said(rest))?,
+        }
+
+        if self.number.0 < 0 {
+            write!(f, " BC")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl NumbersToWords<Time> {
+    // Midnight and noon are named rather than numbered
+    fn hour(&self, f: &mut core::fmt::Formatter<'_>, hour: u8) -> core::fmt::Result {
+        match hour % 24 {
+            0 => write!(f, "midnight"),
+            12 => write!(f, "noon"),
+            hour => write!(f, "{}", self.said(hour as u32 % 12)),
+        }
+    }
+
+    fn spoken(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let Time { hour, minute } = self.number;
+        match minute {
+            0 if hour % 12 == 0 => self.hour(f, hour),
+            0 => write!(f, "{} o'clock", self.said(hour as u32 % 12)),
+            15 => {
+                write!(f, "quarter past ")?;
+                self.hour(f, hour)
+            }
+            30 => {
+                write!(f, "half past ")?;
+                self.hour(f, hour)
+            }
+            45 => {
+                write!(f, "quarter to ")?;
+                self.hour(f, hour + 1)
+            }
+            _ => {
+                let (minutes, relation, hour) = match minute {
+                    1..=29 => (minute, "past", hour),
+                    _ => (60 - minute, "to", hour + 1),
+                };
+
+                // Only multiples of five are said without "minutes"
+                write!(f, "{}", self.said(minutes as u32))?;
+                match minutes {
+                    _ if minutes % 5 == 0 => write!(f, " {} ", relation)?,
+                    1 => write!(f, " minute {} ", relation)?,
+                    _ => write!(f, " minutes {} ", relation)?,
+                }
+
+                self.hour(f, hour)
+            }
+        }
+    }
+
+    fn digital(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let Time { hour, minute } = self.number;
+        let hour = match hour % 12 {
+            0 => 12,
+            hour => hour as u32,
+        };
+
+        match minute {
+            0 => write!(f, "{} o'clock", self.said(hour)),
+            1..=9 => write!(f, "{} oh {}", self.said(hour), self.said(minute as u32)),
+            _ => write!(f, "{} {}", self.said(hour), self.said(minute as u32)),
+        }
+    }
+
+    // Every digit pair is said, with a leading zero read as "zero"
+    fn military(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let Time { hour, minute } = self.number;
+        let pair = |f: &mut core::fmt::Formatter<'_>, n: u8| match n {
+            0..=9 => write!(f, "zero {}", self.said(n as u32)),
+            _ => write!(f, "{}", self.said(n as u32)),
+        };
+
+        pair(f, hour)?;
+        match minute {
+            0 => write!(f, " hundred")?,
+            _ => {
+                write!(f, " ")?;
+                pair(f, minute)?;
+            }
+        }
+
+        write!(f, " hours")
+    }
+}
+
+impl Render for NumbersToWords<Time> {
+    fn render(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.settings.time_style {
+            TimeStyle::Spoken => self.spoken(f),
+            TimeStyle::Digital => self.digital(f),
+            TimeStyle::Military => self.military(f),
+        }
+    }
+}
+
+impl Render for NumbersToWords<Date> {
+    fn render(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let Date { year, month, day } = self.number;
+        let day = self.with_number(day as u128).with_ordinal(true);
+
+        write!(f, "the {} of {}", day, MONTHS[month as usize - 1])?;
+        if let Some(year) = year {
+            write!(f, ", {}", self.with_number(year))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{format, string::String};
+
+    use super::{Date, Time, TimeStyle, Year};
+    use crate::{numbers_to_words::NumbersToWords, render_options::RenderOptions};
+
+    fn year(year: i32) -> String {
+        format!("{}", NumbersToWords::new(Year(year)))
+    }
+
+    fn time(hour: u8, minute: u8, style: TimeStyle) -> String {
+        format!("{}", NumbersToWords::new(Time::new(hour, minute).unwrap()).time_style(style))
+    }
+
+    #[test]
+    fn displays_years() {
+        assert_eq!(year(1984), "nineteen eighty four");
+        assert_eq!(year(2024), "twenty twenty four");
+        assert_eq!(year(2010), "twenty ten");
+        assert_eq!(year(1900), "nineteen hundred");
+        assert_eq!(year(1905), "nineteen oh five");
+        assert_eq!(year(1066), "ten sixty six");
+        assert_eq!(year(1000), "one thousand");
+        assert_eq!(year(2000), "two thousand");
+        assert_eq!(year(2005), "two thousand and five");
+        assert_eq!(year(2105), "twenty one oh five");
+        assert_eq!(year(476), "four hundred and seventy six");
+        assert_eq!(year(10_191), "ten thousand one hundred and ninety one");
+        assert_eq!(year(-44), "forty four BC");
+        assert_eq!(year(0), "zero");
+    }
+
+    #[test]
+    fn displays_years_with_options() {
+        let american = |year: i32| format!("{}", NumbersToWords::new(Year(year)).options(RenderOptions::AMERICAN));
+
+        assert_eq!(american(1984), "nineteen eighty-four");
+        assert_eq!(american(2005), "two thousand five");
+        assert_eq!(american(2024), "twenty twenty-four");
+    }
+
+    #[test]
+    fn displays_spoken_times() {
+        let spoken = |hour, minute| time(hour, minute, TimeStyle::Spoken);
+
+        assert_eq!(spoken(3, 0), "three o'clock");
+        assert_eq!(spoken(15, 0), "three o'clock");
+        assert_eq!(spoken(0, 0), "midnight");
+        assert_eq!(spoken(12, 0), "noon");
+        assert_eq!(spoken(15, 15), "quarter past three");
+        assert_eq!(spoken(15, 30), "half past three");
+        assert_eq!(spoken(15, 45), "quarter to four");
+        assert_eq!(spoken(3, 10), "ten past three");
+        assert_eq!(spoken(3, 25), "twenty five past three");
+        assert_eq!(spoken(3, 1), "one minute past three");
+        assert_eq!(spoken(3, 7), "seven minutes past three");
+        assert_eq!(spoken(3, 59), "one minute to four");
+        assert_eq!(spoken(3, 38), "twenty two minutes to four");
+        assert_eq!(spoken(23, 50), "ten to midnight");
+        assert_eq!(spoken(0, 15), "quarter past midnight");
+        assert_eq!(spoken(11, 45), "quarter to noon");
+        assert_eq!(spoken(12, 30), "half past noon");
+    }
+
+    #[test]
+    fn displays_digital_times() {
+        let digital = |hour, minute| time(hour, minute, TimeStyle::Digital);
+
+        assert_eq!(digital(3, 15), "three fifteen");
+        assert_eq!(digital(15, 5), "three oh five");
+        assert_eq!(digital(9, 0), "nine o'clock");
+        assert_eq!(digital(0, 0), "twelve o'clock");
+        assert_eq!(digital(12, 59), "twelve fifty nine");
+    }
+
+    #[test]
+    fn displays_military_times() {
+        let military = |hour, minute| time(hour, minute, TimeStyle::Military);
+
+        assert_eq!(military(14, 0), "fourteen hundred hours");
+        assert_eq!(military(15, 15), "fifteen fifteen hours");
+        assert_eq!(military(9, 30), "zero nine thirty hours");
+        assert_eq!(military(14, 5), "fourteen zero five hours");
+        assert_eq!(military(0, 0), "zero zero hundred hours");
+        assert_eq!(military(23, 59), "twenty three fifty nine hours");
+    }
+
+    #[test]
+    fn rejects_times_off_the_clock() {
+        assert_eq!(Time::new(24, 0), None);
+        assert_eq!(Time::new(12, 60), None);
+        assert!(Time::new(23, 59).is_some());
+    }
+
+    #[test]
+    fn displays_dates() {
+        let date = |date: Date| format!("{}", NumbersToWords::new(date));
+
+        assert_eq!(date(Date::new(21, 3).unwrap()), "the twenty first of March");
+        assert_eq!(date(Date::new(1, 1).unwrap()), "the first of January");
+        assert_eq!(date(Date::new(12, 12).unwrap()), "the twelfth of December");
+        assert_eq!(date(Date::new(30, 11).unwrap()), "the thirtieth of November");
+        assert_eq!(date(Date::new(4, 7).unwrap().year(1776).unwrap()), "the fourth of July, seventeen seventy six");
+        assert_eq!(date(Date::new(29, 2).unwrap().year(2000).unwrap()), "the twenty ninth of February, two thousand");
+
+        let hyphenated = NumbersToWords::new(Date::new(21, 3).unwrap().year(1984).unwrap()).options(RenderOptions::BRITISH);
+        assert_eq!(format!("{hyphenated}"), "the twenty-first of March, nineteen eighty-four");
+    }
+
+    #[test]
+    fn rejects_days_off_the_calendar() {
+        assert_eq!(Date::new(0, 1), None);
+        assert_eq!(Date::new(32, 1), None);
+        assert_eq!(Date::new(31, 4), None);
+        assert_eq!(Date::new(1, 0), None);
+        assert_eq!(Date::new(1, 13), None);
+        assert_eq!(Date::new(29, 2).unwrap().year(1900), None);
+        assert_eq!(Date::new(29, 2).unwrap().year(2023), None);
+        assert!(Date::new(29, 2).unwrap().year(2024).is_some());
+    }
+}
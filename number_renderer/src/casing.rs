@@ -0,0 +1,164 @@
+use core::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Casing {
+    // The words as they are written, "one thousand two hundred"
+    #[default]
+    Lower,
+    // "One thousand two hundred"
+    Sentence,
+    // "One Thousand Two Hundred", with each part of a hyphenated word capitalised but "a",
+    // "and" and "of" left alone after the first word, "The Fourth of July"
+    Title,
+    // "ONE THOUSAND TWO HUNDRED"
+    Upper,
+}
+
+// Words kept lower case in titles, unless they come first
+const MINOR_WORDS: [&str; 3] = ["a", "and", "of"];
+
+// As long as the longest minor word, any longer word is capitalised
+const HELD: usize = 3;
+
+// Changes the case of words as they are written, so the finished string needs no second pass.
+// In titles the start of each word is held back until it is known not to be a minor word,
+// so flush has to be called once everything is written
+pub(crate) struct CaseWriter<W: fmt::Write> {
+    inner: W,
+    casing: Casing,
+    word_start: bool,
+    started: bool,
+    held: [char; HELD],
+    held_len: usize,
+}
+
+impl<W: fmt::Write> CaseWriter<W> {
+    pub(crate) fn new(inner: W, casing: Casing) -> Self {
+        CaseWriter {
+            inner,
+            casing,
+            word_start: true,
+            started: false,
+            held: ['\0'; HELD],
+            held_len: 0,
+        }
+    }
+
+    fn write_upper(&mut self, c: char) -> fmt::Result {
+        c.to_uppercase().try_for_each(|upper| self.inner.write_char(upper))
+    }
+
+    // Writes out the held start of a word, capitalised unless it is the whole of a minor word
+    fn release(&mut self, whole: bool) -> fmt::Result {
+        let held = self.held;
+        let held = &held[..self.held_len];
+        self.held_len = 0;
+
+        let Some((first, rest)) = held.split_first() else {
+            return Ok(());
+        };
+
+        let minor = whole && MINOR_WORDS.iter().any(|word| word.chars().eq(held.iter().copied()));
+        match minor {
+            true => self.inner.write_char(*first)?,
+            false => self.write_upper(*first)?,
+        }
+
+        rest.iter().try_for_each(|c| self.inner.write_char(*c))
+    }
+
+    pub(crate) fn flush(&mut self) -> fmt::Result {
+        self.release(true)
+    }
+
+    fn write_title(&mut self, c: char) -> fmt::Result {
+        if self.held_len > 0 {
+            if !c.is_alphabetic() {
+                self.release(true)?;
+            } else if self.held_len == HELD {
+                self.release(false)?;
+            } else {
+                self.held[self.held_len] = c;
+                self.held_len += 1;
+                return Ok(());
+            }
+        }
+
+        let word_start = c.is_alphabetic() && self.word_start;
+        let first = !self.started;
+        self.started |= c.is_alphabetic();
+        self.word_start = c.is_whitespace() || c == '-';
+
+        match (word_start, first) {
+            (true, true) => self.write_upper(c),
+            (true, false) => {
+                self.held[0] = c;
+                self.held_len = 1;
+                Ok(())
+            }
+            (false, _) => self.inner.write_char(c),
+        }
+    }
+}
+
+impl<W: fmt::Write> fmt::Write for CaseWriter<W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        match self.casing {
+            Casing::Lower => self.inner.write_str(s),
+            Casing::Upper => s.chars().try_for_each(|c| self.write_upper(c)),
+            Casing::Sentence if self.started => self.inner.write_str(s),
+            Casing::Sentence => s.chars().try_for_each(|c| {
+                let capital = c.is_alphabetic() && !self.started;
+                self.started |= c.is_alphabetic();
+
+                match capital {
+                    true => self.write_upper(c),
+                    false => self.inner.write_char(c),
+                }
+            }),
+            Casing::Title => s.chars().try_for_each(|c| self.write_title(c)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::String;
+
+    use core::fmt::Write;
+
+    use super::{CaseWriter, Casing};
+
+    fn cased(casing: Casing, parts: &[&str]) -> String {
+        let mut out = CaseWriter::new(String::new(), casing);
+        parts.iter().for_each(|part| out.write_str(part).unwrap());
+        out.flush().unwrap();
+        out.inner
+    }
+
+    #[test]
+    fn cases_across_writes() {
+        let parts = ["one thousand", " two", " hundred and ", "forty-two"];
+
+        assert_eq!(cased(Casing::Lower, &parts), "one thousand two hundred and forty-two");
+        assert_eq!(cased(Casing::Sentence, &parts), "One thousand two hundred and forty-two");
+        assert_eq!(cased(Casing::Title, &parts), "One Thousand Two Hundred and Forty-Two");
+        assert_eq!(cased(Casing::Upper, &parts), "ONE THOUSAND TWO HUNDRED AND FORTY-TWO");
+    }
+
+    #[test]
+    fn cases_from_the_first_letter() {
+        assert_eq!(cased(Casing::Sentence, &["", "  ", "-", "five"]), "  -Five");
+        assert_eq!(cased(Casing::Title, &["the fourth of july"]), "The Fourth of July");
+        assert_eq!(cased(Casing::Upper, &["dreißig"]), "DREISSIG");
+    }
+
+    #[test]
+    fn leaves_minor_words_in_titles() {
+        assert_eq!(cased(Casing::Title, &["and a half"]), "And a Half");
+        assert_eq!(cased(Casing::Title, &["dollars a", "nd fifty cents"]), "Dollars and Fifty Cents");
+        assert_eq!(cased(Casing::Title, &["half an", "other offer of"]), "Half Another Offer of");
+        assert_eq!(cased(Casing::Title, &["one and", "-and"]), "One and-and");
+        assert_eq!(cased(Casing::Title, &["ten o", "f", " ANDES"]), "Ten of ANDES");
+    }
+}
@@ -0,0 +1,254 @@
+use core::fmt::Display;
+
+use crate::{
+    languages::{Language, PluralCategory},
+    numbers_to_words::{NumbersToWords, Render},
+};
+
+// The forms a noun takes for each plural category, with the other form used for any not given
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Noun {
+    one: &'static str,
+    other: &'static str,
+    zero: Option<&'static str>,
+    two: Option<&'static str>,
+    few: Option<&'static str>,
+    many: Option<&'static str>,
+}
+
+impl Noun {
+    pub fn new(one: &'static str, other: &'static str) -> Self {
+        Noun {
+            one,
+            other,
+            zero: None,
+            two: None,
+            few: None,
+            many: None,
+        }
+    }
+
+    pub fn zero(mut self, form: &'static str) -> Self {
+        self.zero = Some(form);
+        self
+    }
+
+    pub fn two(mut self, form: &'static str) -> Self {
+        self.two = Some(form);
+        self
+    }
+
+    // "файла" after two to four in Russian
+    pub fn few(mut self, form: &'static str) -> Self {
+        self.few = Some(form);
+        self
+    }
+
+    // "файлов" after five upwards in Russian
+    pub fn many(mut self, form: &'static str) -> Self {
+        self.many = Some(form);
+        self
+    }
+
+    pub fn form(&self, category: PluralCategory) -> &'static str {
+        let form = match category {
+            PluralCategory::One => Some(self.one),
+            PluralCategory::Zero => self.zero,
+            PluralCategory::Two => self.two,
+            PluralCategory::Few => self.few,
+            PluralCategory::Many => self.many,
+            PluralCategory::Other => None,
+        };
+
+        form.unwrap_or(self.other)
+    }
+}
+
+// A count and the noun it counts, "twenty one files", with the noun chosen by the
+// plural rules of the language it is rendered in
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Counted<N> {
+    pub(crate) count: N,
+    noun: Noun,
+    numeric: bool,
+}
+
+impl<N> Counted<N> {
+    pub fn new(count: N, noun: Noun) -> Self {
+        Counted {
+            count,
+            noun,
+            numeric: false,
+        }
+    }
+
+    // Writes the count as digits, "21 files", keeping the noun the words would have
+    pub fn numeric(mut self) -> Self {
+        self.numeric = true;
+        self
+    }
+}
+
+impl<N: Copy + Display, L: Language> NumbersToWords<Counted<N>, L>
+where
+    NumbersToWords<N, L>: Display,
+{
+    // Negative counts take the noun of their magnitude, "minus one degree"
+    fn write_counted(&self, f: &mut core::fmt::Formatter<'_>, magnitude: u128) -> core::fmt::Result {
+        let Counted { count, noun, numeric } = self.number;
+        match numeric {
+            true => write!(f, "{}", count)?,
+            false => {
+                let mut words = self.with_number(count).with_ordinal(false);
+                words.settings.before_noun = true;
+                write!(f, "{}", words)?
+            }
+        }
+
+        write!(f, " {}", noun.form(self.language.plural_category(magnitude)))
+    }
+}
+
+macro_rules! counted_unsigned {
+    ($($t:ty),*) => {
+        $(
+            impl<L: Language> Render for NumbersToWords<Counted<$t>, L> {
+                fn render(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    self.write_counted(f, self.number.count as u128)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! counted_signed {
+    ($($t:ty),*) => {
+        $(
+            impl<L: Language> Render for NumbersToWords<Counted<$t>, L> {
+                fn render(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    self.write_counted(f, self.number.count.unsigned_abs() as u128)
+                }
+            }
+        )*
+    };
+}
+
+counted_unsigned!(u8, u16, u32, u64, u128, usize);
+counted_signed!(i8, i16, i32, i64, i128, isize);
+
+#[cfg(test)]
+mod tests {
+    use alloc::{format, string::String};
+
+    use super::{Counted, Noun};
+    use crate::{
+        casing::Casing,
+        languages::{French, Gender, German, PluralCategory, Polish, Russian, Spanish},
+        numbers_to_words::NumbersToWords,
+        render_options::RenderOptions,
+    };
+
+    fn files(count: i64) -> String {
+        format!("{}", NumbersToWords::new(Counted::new(count, Noun::new("file", "files"))))
+    }
+
+    #[test]
+    fn displays_counted_nouns() {
+        assert_eq!(files(1), "one file");
+        assert_eq!(files(0), "zero files");
+        assert_eq!(files(2), "two files");
+        assert_eq!(files(21), "twenty one files");
+        assert_eq!(files(-1), "minus one file");
+        assert_eq!(files(1_000_000), "one million files");
+
+        let actual = NumbersToWords::new(Counted::new(21_u8, Noun::new("file", "files"))).options(RenderOptions::AMERICAN);
+        assert_eq!(format!("{actual}"), "twenty-one files");
+
+        let actual = NumbersToWords::new(Counted::new(1_u8, Noun::new("sheep", "sheep"))).casing(Casing::Sentence);
+        assert_eq!(format!("{actual}"), "One sheep");
+    }
+
+    #[test]
+    fn displays_numeric_counts() {
+        let numeric = |count: i32| format!("{}", NumbersToWords::new(Counted::new(count, Noun::new("file", "files")).numeric()));
+
+        assert_eq!(numeric(1), "1 file");
+        assert_eq!(numeric(21), "21 files");
+        assert_eq!(numeric(0), "0 files");
+        assert_eq!(numeric(-1), "-1 file");
+    }
+
+    #[test]
+    fn displays_plural_categories() {
+        let noun = Noun::new("файл", "файла").few("файла").many("файлов");
+        let russian = |count: u32| format!("{}", NumbersToWords::new(Counted::new(count, noun).numeric()).language(Russian));
+
+        assert_eq!(russian(1), "1 файл");
+        assert_eq!(russian(21), "21 файл");
+        assert_eq!(russian(3), "3 файла");
+        assert_eq!(russian(11), "11 файлов");
+        assert_eq!(russian(25), "25 файлов");
+
+        let actual = NumbersToWords::new(Counted::new(22_u32, Noun::new("книга", "книги").few("книги").many("книг")))
+            .language(Russian)
+            .gender(Gender::Feminine);
+        assert_eq!(format!("{actual}"), "двадцать две книги");
+
+        let actual = NumbersToWords::new(Counted::new(5_u32, Noun::new("plik", "pliku").few("pliki").many("plików"))).language(Polish);
+        assert_eq!(format!("{actual}"), "pięć plików");
+
+        let fichier = Noun::new("fichier", "fichiers").many("de fichiers");
+        let french = |count: u32| format!("{}", NumbersToWords::new(Counted::new(count, fichier)).language(French));
+
+        assert_eq!(french(0), "zéro fichier");
+        assert_eq!(french(2), "deux fichiers");
+        assert_eq!(french(1_000_000), "un million de fichiers");
+    }
+
+    #[test]
+    fn displays_counts_before_nouns() {
+        let archivo = Noun::new("archivo", "archivos").many("de archivos");
+        let spanish = |count: u32| format!("{}", NumbersToWords::new(Counted::new(count, archivo)).language(Spanish));
+
+        assert_eq!(spanish(1), "un archivo");
+        assert_eq!(spanish(21), "veintiún archivos");
+        assert_eq!(spanish(101), "ciento un archivos");
+        assert_eq!(spanish(1_000_000), "un millón de archivos");
+
+        let carpeta = |count: u32| {
+            let counted = Counted::new(count, Noun::new("carpeta", "carpetas"));
+            format!("{}", NumbersToWords::new(counted).language(Spanish).gender(Gender::Feminine))
+        };
+
+        assert_eq!(carpeta(1), "una carpeta");
+        assert_eq!(carpeta(21), "veintiuna carpetas");
+
+        let datei = |count: u32| {
+            let counted = Counted::new(count, Noun::new("Datei", "Dateien"));
+            format!("{}", NumbersToWords::new(counted).language(German).gender(Gender::Feminine))
+        };
+
+        assert_eq!(datei(1), "eine Datei");
+        assert_eq!(datei(21), "einundzwanzig Dateien");
+        assert_eq!(datei(101), "einhunderteine Dateien");
+
+        let ordner = |count: u32| format!("{}", NumbersToWords::new(Counted::new(count, Noun::new("Ordner", "Ordner"))).language(German));
+
+        assert_eq!(ordner(1), "ein Ordner");
+        assert_eq!(ordner(21), "einundzwanzig Ordner");
+
+        assert_eq!(format!("{}", NumbersToWords::new(1_u8).language(Spanish)), "uno");
+        assert_eq!(format!("{}", NumbersToWords::new(1_u8).language(German)), "eins");
+    }
+
+    #[test]
+    fn falls_back_to_other() {
+        let noun = Noun::new("file", "files");
+
+        assert_eq!(noun.form(PluralCategory::One), "file");
+        assert_eq!(noun.form(PluralCategory::Few), "files");
+        assert_eq!(noun.form(PluralCategory::Zero), "files");
+        assert_eq!(noun.zero("no files").form(PluralCategory::Zero), "no files");
+        assert_eq!(noun.two("pair of files").form(PluralCategory::Two), "pair of files");
+    }
+}
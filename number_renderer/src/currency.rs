@@ -0,0 +1,343 @@
+use alloc::format;
+use core::fmt::Display;
+
+use crate::{
+    decimal::Decimal,
+    numbers_to_words::{NumbersToWords, Render},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CurrencyUnit {
+    pub singular: &'static str,
+    pub plural: &'static str,
+}
+
+impl CurrencyUnit {
+    fn name(&self, amount: u128) -> &'static str {
+        match amount {
+            1 => self.singular,
+            _ => self.plural,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Currency {
+    pub code: &'static str,
+    pub major: CurrencyUnit,
+    pub minor: CurrencyUnit,
+    // How many minor units make a major unit, as a power of ten
+    pub precision: u32,
+}
+
+impl Currency {
+    pub const GBP: Currency = Currency {
+        code: "GBP",
+        major: CurrencyUnit { singular: "pound", plural: "pounds" },
+        minor: CurrencyUnit { singular: "penny", plural: "pence" },
+        precision: 2,
+    };
+
+    pub const USD: Currency = Currency {
+        code: "USD",
+        major: CurrencyUnit { singular: "dollar", plural: "dollars" },
+        minor: CurrencyUnit { singular: "cent", plural: "cents" },
+        precision: 2,
+    };
+
+    pub const EUR: Currency = Currency {
+        code: "EUR",
+        major: CurrencyUnit { singular: "euro", plural: "euros" },
+        minor: CurrencyUnit { singular: "cent", plural: "cents" },
+        precision: 2,
+    };
+
+    pub const JPY: Currency = Currency {
+        code: "JPY",
+        major: CurrencyUnit { singular: "yen", plural: "yen" },
+        minor: CurrencyUnit { singular: "sen", plural: "sen" },
+        precision: 0,
+    };
+
+    pub const KWD: Currency = Currency {
+        code: "KWD",
+        major: CurrencyUnit { singular: "dinar", plural: "dinars" },
+        minor: CurrencyUnit { singular: "fils", plural: "fils" },
+        precision: 3,
+    };
+
+    pub fn from_code(code: &str) -> Option<Currency> {
+        Currency::from_code_in(&CURRENCIES, code)
+    }
+
+    // Looks the code up in a table of the caller's own, which can extend or replace CURRENCIES
+    pub fn from_code_in(table: &[Currency], code: &str) -> Option<Currency> {
+        table
+            .iter()
+            .find(|currency| currency.code.eq_ignore_ascii_case(code))
+            .copied()
+    }
+
+    // The major and minor parts of an amount. With a precision too large for a major unit
+    // to fit in a u128, every amount is all minor units
+    fn split(&self, minor_units: u128) -> (u128, u128) {
+        match 10_u128.checked_pow(self.precision) {
+            Some(per_major) => (minor_units / per_major, minor_units % per_major),
+            None => (0, minor_units),
+        }
+    }
+}
+
+pub const CURRENCIES: [Currency; 5] = [
+    Currency::GBP,
+    Currency::USD,
+    Currency::EUR,
+    Currency::JPY,
+    Currency::KWD,
+];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MinorStyle {
+    // "and fifty six pence"
+    Words,
+    // "and 56/100", as written on cheques
+    Fraction,
+}
+
+// An amount of money held exactly as a count of minor units
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Money {
+    minor_units: i128,
+    currency: Currency,
+    minor_style: MinorStyle,
+}
+
+impl Money {
+    pub fn new(minor_units: i128, currency: Currency) -> Self {
+        Money {
+            minor_units,
+            currency,
+            minor_style: MinorStyle::Words,
+        }
+    }
+
+    // None when the amount has more decimal places than the currency, or does not fit
+    pub fn from_decimal(amount: &Decimal, currency: Currency) -> Option<Self> {
+        let places = currency.precision as usize;
+        let fraction = amount.fraction().trim_end_matches('0');
+        if fraction.len() > places {
+            return None;
+        }
+
+        let minor_units = format!("{}{:0<places$}", amount.integer(), fraction)
+            .parse::<i128>()
+            .ok()?;
+
+        Some(Money::new(
+            if amount.is_negative() { -minor_units } else { minor_units },
+            currency,
+        ))
+    }
+
+    pub fn minor_style(mut self, style: MinorStyle) -> Self {
+        self.minor_style = style;
+        self
+    }
+
+    pub fn minor_units(&self) -> i128 {
+        self.minor_units
+    }
+
+    pub fn currency(&self) -> Currency {
+        self.currency
+    }
+}
+
+impl Display for Money {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let places = self.currency.precision as usize;
+        let (major, minor) = self.currency.split(self.minor_units.unsigned_abs());
+
+        if self.minor_units < 0 {
+            write!(f, "-")?;
+        }
+
+        write!(f, "{}", major)?;
+        if places > 0 {
+            write!(f, ".{:0>places$}", minor)?;
+        }
+
+        write!(f, " {}", self.currency.code)
+    }
+}
+
+impl Render for NumbersToWords<Money> {
+    fn render(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let Money {
+            minor_units,
+            currency,
+            minor_style,
+        } = self.number;
+
+        let (major, minor) = currency.split(minor_units.unsigned_abs());
+        let places = currency.precision as usize;
+
+        if minor_units < 0 {
+            write!(f, "{} ", self.negative())?;
+        }
+
+        // The units are always joined by "and", whatever the options say about "hundred and one",
+        // as a cheque reads "dollars and 56/100"
+        match minor_style {
+            MinorStyle::Words if major == 0 && minor > 0 => {
+                write!(f, "{} {}", self.with_number(minor), currency.minor.name(minor))
+            }
+            MinorStyle::Words if minor == 0 => {
+                write!(f, "{} {}", self.with_number(major), currency.major.name(major))
+            }
+            MinorStyle::Words => write!(
+                f,
+                "{} {} and {} {}",
+                self.with_number(major),
+                currency.major.name(major),
+                self.with_number(minor),
+                currency.minor.name(minor)
+            ),
+            MinorStyle::Fraction if currency.precision == 0 => {
+                write!(f, "{} {}", self.with_number(major), currency.major.name(major))
+            }
+            // The denominator is written as a one and its zeros, as it may not fit in a u128
+            MinorStyle::Fraction => write!(
+                f,
+                "{} {} and {:0>places$}/1{:0>places$}",
+                self.with_number(major),
+                currency.major.name(major),
+                minor,
+                ""
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{format, string::{String, ToString}};
+
+    use super::{Currency, CurrencyUnit, MinorStyle, Money, CURRENCIES};
+    use crate::{decimal::Decimal, numbers_to_words::NumbersToWords, render_options::RenderOptions};
+
+    fn words(money: Money) -> String {
+        format!("{}", NumbersToWords::new(money))
+    }
+
+    #[test]
+    fn displays_pounds_and_pence() {
+        assert_eq!(words(Money::new(123456, Currency::GBP)), "one thousand two hundred and thirty four pounds and fifty six pence");
+        assert_eq!(words(Money::new(101, Currency::GBP)), "one pound and one penny");
+        assert_eq!(words(Money::new(500, Currency::GBP)), "five pounds");
+        assert_eq!(words(Money::new(99, Currency::GBP)), "ninety nine pence");
+        assert_eq!(words(Money::new(0, Currency::GBP)), "zero pounds");
+        assert_eq!(words(Money::new(-250, Currency::GBP)), "minus two pounds and fifty pence");
+    }
+
+    #[test]
+    fn displays_cheque_fractions() {
+        let cheque = |minor_units| words(Money::new(minor_units, Currency::USD).minor_style(MinorStyle::Fraction));
+
+        assert_eq!(cheque(123456), "one thousand two hundred and thirty four dollars and 56/100");
+        assert_eq!(cheque(100), "one dollar and 00/100");
+        assert_eq!(cheque(7), "zero dollars and 07/100");
+
+        let actual = words(Money::new(1_234_567, Currency::KWD).minor_style(MinorStyle::Fraction));
+        assert_eq!(actual, "one thousand two hundred and thirty four dinars and 567/1000");
+
+        let actual = words(Money::new(5000, Currency::JPY).minor_style(MinorStyle::Fraction));
+        assert_eq!(actual, "five thousand yen");
+    }
+
+    #[test]
+    fn displays_exact_large_amounts() {
+        let actual = words(Money::new(i128::MAX, Currency::EUR));
+        assert!(actual.ends_with("thirty seven billion one hundred and fifty eight million eight hundred and forty one thousand and fifty seven euros and twenty seven cents"));
+
+        let actual = words(Money::new(900_719_925_474_099_399, Currency::USD));
+        assert_eq!(actual, "nine quadrillion seven trillion one hundred and ninety nine billion two hundred and fifty four million seven hundred and forty thousand nine hundred and ninety three dollars and ninety nine cents");
+    }
+
+    #[test]
+    fn displays_custom_currencies() {
+        let rupee = Currency {
+            code: "INR",
+            major: CurrencyUnit { singular: "rupee", plural: "rupees" },
+            minor: CurrencyUnit { singular: "paisa", plural: "paise" },
+            precision: 2,
+        };
+
+        assert_eq!(words(Money::new(150, rupee)), "one rupee and fifty paise");
+        assert_eq!(words(Money::new(-1, rupee)), "minus one paisa");
+
+        let actual = NumbersToWords::new(Money::new(-1, rupee)).negative_word("less");
+        assert_eq!(format!("{actual}"), "less one paisa");
+
+        let tiny = Currency {
+            code: "XTN",
+            major: CurrencyUnit { singular: "whole", plural: "wholes" },
+            minor: CurrencyUnit { singular: "part", plural: "parts" },
+            precision: 40,
+        };
+
+        assert_eq!(words(Money::new(7, tiny)), "seven parts");
+        assert_eq!(Money::new(7, tiny).to_string(), "0.0000000000000000000000000000000000000007 XTN");
+
+        let actual = words(Money::new(7, tiny).minor_style(MinorStyle::Fraction));
+        assert_eq!(actual, "zero wholes and 0000000000000000000000000000000000000007/10000000000000000000000000000000000000000");
+    }
+
+    #[test]
+    fn follows_render_options() {
+        let american = |money: Money| format!("{}", NumbersToWords::new(money).options(RenderOptions::AMERICAN));
+
+        assert_eq!(american(Money::new(12350, Currency::USD)), "one hundred twenty-three dollars and fifty cents");
+        assert_eq!(american(Money::new(150, Currency::USD).minor_style(MinorStyle::Fraction)), "one dollar and 50/100");
+
+        let actual = NumbersToWords::new(Money::new(12350, Currency::GBP)).options(RenderOptions::BRITISH);
+        assert_eq!(format!("{actual}"), "one hundred and twenty-three pounds and fifty pence");
+    }
+
+    #[test]
+    fn finds_currencies_by_code() {
+        assert_eq!(Currency::from_code("gbp"), Some(Currency::GBP));
+        assert_eq!(Currency::from_code("JPY"), Some(Currency::JPY));
+        assert_eq!(Currency::from_code("XXX"), None);
+
+        let rupee = Currency {
+            code: "INR",
+            major: CurrencyUnit { singular: "rupee", plural: "rupees" },
+            minor: CurrencyUnit { singular: "paisa", plural: "paise" },
+            precision: 2,
+        };
+        let table = [CURRENCIES.as_slice(), &[rupee]].concat();
+
+        assert_eq!(Currency::from_code_in(&table, "inr"), Some(rupee));
+        assert_eq!(Currency::from_code_in(&table, "EUR"), Some(Currency::EUR));
+        assert_eq!(Currency::from_code_in(&[rupee], "EUR"), None);
+    }
+
+    #[test]
+    fn converts_decimals() {
+        let decimal = |input: &str| input.parse::<Decimal>().unwrap();
+
+        assert_eq!(Money::from_decimal(&decimal("1234.56"), Currency::GBP), Some(Money::new(123456, Currency::GBP)));
+        assert_eq!(Money::from_decimal(&decimal("-0.5"), Currency::GBP), Some(Money::new(-50, Currency::GBP)));
+        assert_eq!(Money::from_decimal(&decimal("3.100"), Currency::USD), Some(Money::new(310, Currency::USD)));
+        assert_eq!(Money::from_decimal(&decimal("3.105"), Currency::USD), None);
+        assert_eq!(Money::from_decimal(&decimal("12"), Currency::JPY), Some(Money::new(12, Currency::JPY)));
+    }
+
+    #[test]
+    fn displays_amounts() {
+        assert_eq!(Money::new(123456, Currency::GBP).to_string(), "1234.56 GBP");
+        assert_eq!(Money::new(-7, Currency::USD).to_string(), "-0.07 USD");
+        assert_eq!(Money::new(5000, Currency::JPY).to_string(), "5000 JPY");
+    }
+}
@@ -0,0 +1,337 @@
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+use core::{cmp::Ordering, fmt::Display, str::FromStr};
+
+use crate::{
+    digit_string::DigitString,
+    numbers_to_words::{DecimalStyle, NumbersToWords, Render},
+    scales::scale_name,
+    validation_errors::ParseDigitsError,
+};
+
+// An exact decimal number, with the fractional digits kept as written
+#[derive(Debug, Clone)]
+pub struct Decimal {
+    negative: bool,
+    integer: DigitString,
+    fraction: String,
+}
+
+impl Decimal {
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    pub fn integer(&self) -> &DigitString {
+        &self.integer
+    }
+
+    pub fn fraction(&self) -> &str {
+        &self.fraction
+    }
+
+    fn is_zero(&self) -> bool {
+        self.integer.digits() == "0" && self.fraction.bytes().all(|digit| digit == b'0')
+    }
+}
+
+impl FromStr for Decimal {
+    type Err = ParseDigitsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negative, start) = match s.as_bytes().first() {
+            Some(b'-') => (true, 1),
+            Some(b'+') => (false, 1),
+            _ => (false, 0),
+        };
+
+        let (integer, fraction) = match s[start..].split_once('.') {
+            Some((integer, fraction)) => (integer, fraction),
+            None => (&s[start..], ""),
+        };
+
+        let point = start + integer.len();
+        if integer.is_empty() && fraction.is_empty() {
+            return Err(ParseDigitsError { position: point });
+        }
+
+        if let Some(position) = integer.find(|c: char| !c.is_ascii_digit()) {
+            return Err(ParseDigitsError {
+                position: start + position,
+            });
+        }
+
+        if let Some(position) = fraction.find(|c: char| !c.is_ascii_digit()) {
+            return Err(ParseDigitsError {
+                position: point + 1 + position,
+            });
+        }
+
+        if s.ends_with('.') {
+            return Err(ParseDigitsError { position: s.len() });
+        }
+
+        let integer = match integer {
+            "" => DigitString::from(0_u128),
+            digits => digits.parse::<DigitString>()?,
+        };
+
+        let mut decimal = Decimal {
+            negative,
+            integer,
+            fraction: fraction.to_string(),
+        };
+        decimal.negative = negative && !decimal.is_zero();
+
+        Ok(decimal)
+    }
+}
+
+impl Display for Decimal {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.negative {
+            write!(f, "-")?;
+        }
+
+        write!(f, "{}", self.integer)?;
+        if !self.fraction.is_empty() {
+            write!(f, ".{}", self.fraction)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Ord for Decimal {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let width = self.fraction.len().max(other.fraction.len());
+        let magnitude = self.integer.cmp(&other.integer).then_with(|| {
+            format!("{:0<width$}", self.fraction).cmp(&format!("{:0<width$}", other.fraction))
+        });
+
+        match (self.negative, other.negative) {
+            (false, false) => magnitude,
+            (true, true) => magnitude.reverse(),
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+        }
+    }
+}
+
+impl PartialOrd for Decimal {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Decimal {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Decimal {}
+
+// "tenths", "hundredths", "ten-thousandths" and so on, for a fraction with this many places
+fn denominator(places: usize, plural: bool) -> String {
+    let name = match (places / 3, places % 3) {
+        (0, 1) => "ten".to_string(),
+        (0, _) => "hundred".to_string(),
+        (index, 0) => scale_name(index),
+        (index, 1) => format!("ten-{}", scale_name(index)),
+        (index, _) => format!("hundred-{}", scale_name(index)),
+    };
+
+    format!("{}th{}", name, if plural { "s" } else { "" })
+}
+
+impl Render for NumbersToWords<Decimal> {
+    fn render(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let Decimal {
+            negative,
+            integer,
+            fraction,
+        } = &self.number;
+
+        if *negative {
+            write!(f, "{} ", self.negative())?;
+        }
+
+        match self.settings.decimal_style {
+            DecimalStyle::Point => {
+                integer.write_magnitude(f, false, self.settings.options)?;
+                if !fraction.is_empty() {
+                    write!(f, " point")?;
+                }
+
+                fraction.bytes().try_for_each(|digit| {
+                    write!(f, " {}", NumbersToWords::new((digit - b'0') as u128))
+                })
+            }
+            DecimalStyle::Fraction => {
+                let numerator = fraction.parse::<DigitString>().unwrap_or(DigitString::from(0_u128));
+                if numerator.digits() == "0" {
+                    return integer.write_magnitude(f, false, self.settings.options);
+                }
+
+                if integer.digits() != "0" {
+                    integer.write_magnitude(f, false, self.settings.options)?;
+                    write!(f, " and ")?;
+                }
+
+                numerator.write_magnitude(f, false, self.settings.options)?;
+                write!(f, " {}", denominator(fraction.len(), numerator.digits() != "1"))
+            }
+        }
+    }
+}
+
+macro_rules! display_float {
+    ($($t:ty),*) => {
+        $(
+            impl Render for NumbersToWords<$t> {
+                fn render(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    if self.number.is_nan() {
+                        return write!(f, "not a number");
+                    }
+
+                    if self.number.is_infinite() {
+                        if self.number.is_sign_negative() {
+                            write!(f, "{} ", self.negative())?;
+                        }
+
+                        return write!(f, "infinity");
+                    }
+
+                    // Display for floats never uses an exponent, so this is always a plain decimal
+                    let decimal = self
+                        .number
+                        .to_string()
+                        .parse::<Decimal>()
+                        .map_err(|_| core::fmt::Error)?;
+
+                    write!(f, "{}", self.with_number(decimal))
+                }
+            }
+        )*
+    };
+}
+
+display_float!(f32, f64);
+
+#[cfg(test)]
+mod tests {
+    use alloc::{format, string::{String, ToString}};
+
+    use super::Decimal;
+    use crate::{
+        numbers_to_words::{DecimalStyle, NumbersToWords},
+        validation_errors::{InputError, ParseDigitsError},
+        validator::Validator,
+    };
+
+    fn decimal(input: &str) -> Decimal {
+        input.parse::<Decimal>().unwrap()
+    }
+
+    fn point(input: &str) -> String {
+        format!("{}", NumbersToWords::new(decimal(input)))
+    }
+
+    fn fraction(input: &str) -> String {
+        format!("{}", NumbersToWords::new(decimal(input)).decimal_style(DecimalStyle::Fraction))
+    }
+
+    #[test]
+    fn parses_decimals() {
+        assert_eq!(decimal("3.141").integer().digits(), "3");
+        assert_eq!(decimal("3.141").fraction(), "141");
+        assert_eq!(decimal(".5").integer().digits(), "0");
+        assert!(!decimal("-0.0").is_negative());
+        assert_eq!(decimal("-2.50").to_string(), "-2.50");
+        assert_eq!(decimal("007").to_string(), "7");
+
+        assert_eq!("".parse::<Decimal>(), Err(ParseDigitsError { position: 0 }));
+        assert_eq!("-".parse::<Decimal>(), Err(ParseDigitsError { position: 1 }));
+        assert_eq!(".".parse::<Decimal>(), Err(ParseDigitsError { position: 0 }));
+        assert_eq!("3.".parse::<Decimal>(), Err(ParseDigitsError { position: 2 }));
+        assert_eq!("3.1x4".parse::<Decimal>(), Err(ParseDigitsError { position: 3 }));
+        assert_eq!("1.2.3".parse::<Decimal>(), Err(ParseDigitsError { position: 3 }));
+        assert_eq!("-1a.5".parse::<Decimal>(), Err(ParseDigitsError { position: 2 }));
+    }
+
+    #[test]
+    fn orders_decimals() {
+        assert_eq!(decimal("3.1"), decimal("3.100"));
+        assert!(decimal("3.09") < decimal("3.1"));
+        assert!(decimal("-3.2") < decimal("-3.1"));
+        assert!(decimal("-0.1") < decimal("0"));
+        assert!(decimal("99.99") < decimal("100"));
+    }
+
+    #[test]
+    fn displays_point_style() {
+        assert_eq!(point("3.141"), "three point one four one");
+        assert_eq!(point("0.07"), "zero point zero seven");
+        assert_eq!(point("-402.5"), "minus four hundred and two point five");
+        assert_eq!(point("2.50"), "two point five zero");
+        assert_eq!(point("1000000.001"), "one million point zero zero one");
+        assert_eq!(point("12"), "twelve");
+    }
+
+    #[test]
+    fn displays_fraction_style() {
+        assert_eq!(fraction("3.07"), "three and seven hundredths");
+        assert_eq!(fraction("3.141"), "three and one hundred and forty one thousandths");
+        assert_eq!(fraction("0.1"), "one tenth");
+        assert_eq!(fraction("-0.5"), "minus five tenths");
+        assert_eq!(fraction("12.0001"), "twelve and one ten-thousandth");
+        assert_eq!(fraction("0.00025"), "twenty five hundred-thousandths");
+        assert_eq!(fraction("1.000001"), "one and one millionth");
+        assert_eq!(fraction("7.0000003"), "seven and three ten-millionths");
+        assert_eq!(fraction("5.00"), "five");
+        assert_eq!(fraction("0.0"), "zero");
+    }
+
+    #[test]
+    fn displays_floats() {
+        assert_eq!(format!("{}", NumbersToWords::<f64>::new(1.625)), "one point six two five");
+        assert_eq!(format!("{}", NumbersToWords::<f64>::new(-0.25)), "minus zero point two five");
+        assert_eq!(format!("{}", NumbersToWords::<f32>::new(1.5)), "one point five");
+        assert_eq!(format!("{}", NumbersToWords::<f64>::new(1e21)), "one sextillion");
+        assert_eq!(format!("{}", NumbersToWords::<f64>::new(f64::NAN)), "not a number");
+        assert_eq!(format!("{}", NumbersToWords::<f64>::new(f64::NEG_INFINITY)), "minus infinity");
+
+        let actual = NumbersToWords::<f64>::new(3.07).decimal_style(DecimalStyle::Fraction);
+        assert_eq!(format!("{actual}"), "three and seven hundredths");
+    }
+
+    #[test]
+    fn validates_decimals() {
+        let actual = NumbersToWords::<Decimal>::validate("3.141", decimal("0"), decimal("10")).unwrap();
+        assert_eq!(format!("{actual}"), "three point one four one");
+
+        let actual = NumbersToWords::<Decimal>::validate("10.01", decimal("0"), decimal("10"));
+        assert!(matches!(actual, Err(InputError::ValidationError { .. })));
+
+        let actual = NumbersToWords::<Decimal>::validate("3.1x4", decimal("0"), decimal("10"));
+        assert!(matches!(actual, Err(InputError::ParseError { position: 3, .. })));
+    }
+
+    #[test]
+    fn validates_floats() {
+        let actual = NumbersToWords::<f64>::validate("-2.5", -10.0, 10.0).unwrap();
+        assert_eq!(format!("{actual}"), "minus two point five");
+
+        let actual = NumbersToWords::<f64>::validate("NaN", -10.0, 10.0);
+        assert!(matches!(actual, Err(InputError::ValidationError { .. })));
+
+        let actual = NumbersToWords::<f64>::validate("2.5.1", -10.0, 10.0);
+        assert!(matches!(actual, Err(InputError::ParseError { position: 3, .. })));
+
+        let actual = NumbersToWords::<f64>::validate("2.5.1", -10.0, 10.0).err().unwrap();
+        assert_eq!(format!("{actual}"), "2.5.1 cannot be parsed, failed at position 3");
+    }
+}
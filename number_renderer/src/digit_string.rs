@@ -0,0 +1,292 @@
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{cmp::Ordering, fmt::Display, str::FromStr};
+
+use crate::{
+    numbers_to_words::{NumbersToWords, Render},
+    ordinal::write_ordinal_word,
+    render_options::{AndPlacement, RenderOptions},
+    scales::{long_scale_name, scale_name},
+    validation_errors::ParseDigitsError,
+};
+
+// An integer of any length, kept as its decimal digits without leading zeros
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DigitString {
+    negative: bool,
+    digits: String,
+}
+
+impl DigitString {
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    pub fn digits(&self) -> &str {
+        &self.digits
+    }
+
+    // Groups of three digits, least significant first
+    fn groups(&self) -> Vec<u128> {
+        let bytes = self.digits.as_bytes();
+        bytes
+            .rchunks(3)
+            .map(|chunk| {
+                chunk
+                    .iter()
+                    .fold(0, |acc, digit| acc * 10 + (digit - b'0') as u128)
+            })
+            .collect()
+    }
+}
+
+impl FromStr for DigitString {
+    type Err = ParseDigitsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negative, start) = match s.as_bytes().first() {
+            Some(b'-') => (true, 1),
+            Some(b'+') => (false, 1),
+            _ => (false, 0),
+        };
+
+        if s.len() == start {
+            return Err(ParseDigitsError { position: start });
+        }
+
+        if let Some(position) = s[start..].find(|c: char| !c.is_ascii_digit()) {
+            return Err(ParseDigitsError {
+                position: start + position,
+            });
+        }
+
+        let digits = s[start..].trim_start_matches('0');
+        if digits.is_empty() {
+            return Ok(DigitString::from(0_u128));
+        }
+
+        Ok(DigitString {
+            negative,
+            digits: digits.to_string(),
+        })
+    }
+}
+
+impl From<u128> for DigitString {
+    fn from(number: u128) -> Self {
+        DigitString {
+            negative: false,
+            digits: number.to_string(),
+        }
+    }
+}
+
+impl From<i128> for DigitString {
+    fn from(number: i128) -> Self {
+        DigitString {
+            negative: number < 0,
+            digits: number.unsigned_abs().to_string(),
+        }
+    }
+}
+
+impl Display for DigitString {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.negative {
+            write!(f, "-")?;
+        }
+
+        write!(f, "{}", self.digits)
+    }
+}
+
+impl Ord for DigitString {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let magnitude = self
+            .digits
+            .len()
+            .cmp(&other.digits.len())
+            .then_with(|| self.digits.cmp(&other.digits));
+
+        match (self.negative, other.negative) {
+            (false, false) => magnitude,
+            (true, true) => magnitude.reverse(),
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+        }
+    }
+}
+
+impl PartialOrd for DigitString {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Render for NumbersToWords<DigitString> {
+    fn render(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.number.negative {
+            write!(f, "{} ", self.negative())?;
+        }
+
+        self.number.write_magnitude(f, self.settings.ordinal, self.settings.options)
+    }
+}
+
+impl DigitString {
+    // Writes the words for the number without its sign
+    pub(crate) fn write_magnitude(
+        &self,
+        f: &mut core::fmt::Formatter<'_>,
+        ordinal: bool,
+        options: RenderOptions,
+    ) -> core::fmt::Result {
+        let group_words = |group: u128, ordinal: bool| NumbersToWords::new(group).options(options).with_ordinal(ordinal);
+        let name = |index: usize| match options.long_scale {
+            true => long_scale_name(index),
+            false => scale_name(index),
+        };
+
+        let groups = self.groups();
+        let Some(last) = groups.iter().position(|group| *group != 0) else {
+            return write!(f, "{}", group_words(0, ordinal));
+        };
+
+        // Mirrors the English renderer, where "and" joins a remainder
+        // smaller than the next scale down, or smaller than a hundred
+        let mut delim = None;
+        for (index, group) in groups.iter().enumerate().rev() {
+            if *group == 0 {
+                continue;
+            }
+
+            if let Some(delim) = delim {
+                write!(f, " {}", delim)?;
+            }
+
+            // Only the very last word of an ordinal changes form
+            let ordinal = ordinal && index == last;
+            match index {
+                0 => write!(f, "{}", group_words(*group, ordinal))?,
+                _ if ordinal => {
+                    write!(f, "{} ", group_words(*group, false))?;
+                    write_ordinal_word(f, &name(index))?;
+                }
+                _ => write!(f, "{} {}", group_words(*group, false), name(index))?,
+            }
+
+            let final_part = index > 0 && groups[1..index].iter().all(|group| *group == 0) && groups[0] < 100;
+            delim = match (index, options.and_placement) {
+                (0, _) => None,
+                (1, _) | (_, AndPlacement::Final) if final_part => Some(options.and()),
+                (1, _) | (_, AndPlacement::Final) => Some(""),
+                (n, AndPlacement::Remainder) if groups[n - 1] == 0 => Some(options.and()),
+                _ => Some(""),
+            };
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{format, string::{String, ToString}, vec, vec::Vec};
+
+    use super::DigitString;
+    use crate::{
+        numbers_to_words::NumbersToWords, validation_errors::InputError,
+        validation_errors::ParseDigitsError, validator::Validator,
+    };
+
+    fn words(input: &str) -> String {
+        let number = input.parse::<DigitString>().unwrap();
+        format!("{}", NumbersToWords::new(number))
+    }
+
+    #[test]
+    fn parses_digit_strings() {
+        assert_eq!("0042".parse::<DigitString>().unwrap().digits(), "42");
+        assert_eq!("-0".parse::<DigitString>().unwrap(), DigitString::from(0_u128));
+        assert_eq!("+7".parse::<DigitString>().unwrap(), DigitString::from(7_u128));
+        assert!("-12".parse::<DigitString>().unwrap().is_negative());
+
+        assert_eq!("".parse::<DigitString>(), Err(ParseDigitsError { position: 0 }));
+        assert_eq!("-".parse::<DigitString>(), Err(ParseDigitsError { position: 1 }));
+        assert_eq!("12a4".parse::<DigitString>(), Err(ParseDigitsError { position: 2 }));
+        assert_eq!("--1".parse::<DigitString>(), Err(ParseDigitsError { position: 1 }));
+    }
+
+    #[test]
+    fn orders_digit_strings() {
+        let sorted = ["-1000", "-999", "-1", "0", "1", "999", "1000", "123456789012345678901234567890123456789012"]
+            .iter()
+            .map(|n| n.parse::<DigitString>().unwrap())
+            .collect::<Vec<DigitString>>();
+
+        sorted
+            .windows(2)
+            .for_each(|pair| assert!(pair[0] < pair[1], "{} < {}", pair[0], pair[1]));
+    }
+
+    #[test]
+    fn displays_beyond_primitive_types() {
+        assert_eq!(words("1000000000000000000000000000000000000000"), "one duodecillion");
+        assert_eq!(
+            words("340282366920938463463374607431768211456"),
+            "three hundred and forty undecillion two hundred and eighty two decillion three hundred and sixty six nonillion nine hundred and twenty octillion nine hundred and thirty eight septillion four hundred and sixty three sextillion four hundred and sixty three quintillion three hundred and seventy four quadrillion six hundred and seven trillion four hundred and thirty one billion seven hundred and sixty eight million two hundred and eleven thousand four hundred and fifty six"
+        );
+        assert_eq!(words(&format!("1{}", "0".repeat(63))), "one vigintillion");
+        assert_eq!(words(&format!("1{}", "0".repeat(303))), "one centillion");
+        assert_eq!(words(&format!("1{}", "0".repeat(3003))), "one millinillion");
+        assert_eq!(words(&format!("-2{}5", "0".repeat(3005))), "minus two millimillion and five");
+    }
+
+    #[test]
+    fn matches_primitive_rendering() {
+        let mut seed = 0x2545_f491_4f6c_dd1d_u64;
+        let mut samples = vec![
+            0,
+            1,
+            -1,
+            100,
+            6047,
+            2000590,
+            1_000_002_000,
+            700000000000590,
+            1_000_000_000_000_000,
+            i64::MAX,
+            i64::MIN,
+        ];
+
+        (0..500).for_each(|i| {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            // Spread the samples over every magnitude, not only the largest
+            samples.push(((seed >> (i % 63)) as i64).wrapping_mul(if i % 2 == 0 { 1 } else { -1 }));
+        });
+
+        samples.iter().for_each(|n| {
+            let expected = format!("{}", NumbersToWords::new(*n));
+            assert_eq!(words(&n.to_string()), expected, "{}", n);
+        });
+    }
+
+    #[test]
+    fn validates_digit_strings() {
+        let low = DigitString::from(0_u128);
+        let high = format!("1{}", "0".repeat(100)).parse::<DigitString>().unwrap();
+
+        let actual = NumbersToWords::<DigitString>::validate("1000000000000000000000000000000000000000000", low.clone(), high.clone()).unwrap();
+        assert_eq!(format!("{actual}"), "one tredecillion");
+
+        let actual = NumbersToWords::<DigitString>::validate(format!("2{}", "0".repeat(100)), low.clone(), high.clone());
+        assert!(matches!(actual, Err(InputError::ValidationError { .. })));
+
+        let actual = NumbersToWords::<DigitString>::validate("12x", low, high);
+        assert!(matches!(actual, Err(InputError::ParseError { position: 2, .. })));
+    }
+}
@@ -0,0 +1,227 @@
+use alloc::string::{String, ToString};
+use core::{fmt::Display, str::FromStr};
+
+use crate::{
+    digit_string::DigitString,
+    numbers_to_words::{NumbersToWords, Render},
+    validation_errors::ParseDigitsError,
+};
+
+const DIGITS: [&str; 10] = ["zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine"];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DigitStyle {
+    // "four two zero"
+    Single,
+    // "double seven", with runs of the same digit said once
+    Compressed,
+    // "forty two, oh seven", the digits read as numbers this many at a time
+    Grouped(usize),
+}
+
+// A phone number, account number or code, kept exactly as written, leading zeros and all
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Digits(String);
+
+impl Digits {
+    pub fn digits(&self) -> &str {
+        &self.0
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl FromStr for Digits {
+    type Err = ParseDigitsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParseDigitsError { position: 0 });
+        }
+
+        match s.find(|c: char| !c.is_ascii_digit()) {
+            Some(position) => Err(ParseDigitsError { position }),
+            None => Ok(Digits(s.to_string())),
+        }
+    }
+}
+
+impl Display for Digits {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+fn digit(byte: u8) -> &'static str {
+    DIGITS[(byte - b'0') as usize]
+}
+
+impl NumbersToWords<Digits> {
+    fn single(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for (index, byte) in self.number.0.bytes().enumerate() {
+            if index > 0 {
+                write!(f, " ")?;
+            }
+
+            write!(f, "{}", digit(byte))?;
+        }
+
+        Ok(())
+    }
+
+    // Four of a kind is said as two doubles, as "triple seven seven" trips up listeners
+    fn compressed(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut first = true;
+        for run in self.number.0.as_bytes().chunk_by(|a, b| a == b) {
+            let mut left = run.len();
+            while left > 0 {
+                let taken = match left {
+                    4 => 2,
+                    _ => left.min(3),
+                };
+
+                if !first {
+                    write!(f, " ")?;
+                }
+                first = false;
+
+                match taken {
+                    3 => write!(f, "triple {}", digit(run[0]))?,
+                    2 => write!(f, "double {}", digit(run[0]))?,
+                    _ => write!(f, "{}", digit(run[0]))?,
+                }
+
+                left -= taken;
+            }
+        }
+
+        Ok(())
+    }
+
+    // Leading zeros in a group are each said as "oh", and what follows them as a number
+    fn grouped(&self, f: &mut core::fmt::Formatter<'_>, size: usize) -> core::fmt::Result {
+        for (index, group) in self.number.0.as_bytes().chunks(size.max(1)).enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+
+            let zeros = group.iter().take_while(|byte| **byte == b'0').count();
+            for zero in 0..zeros {
+                if zero > 0 {
+                    write!(f, " ")?;
+                }
+
+                write!(f, "oh")?;
+            }
+
+            if zeros < group.len() {
+                if zeros > 0 {
+                    write!(f, " ")?;
+                }
+
+                let rest = core::str::from_utf8(&group[zeros..]).map_err(|_| core::fmt::Error)?;
+                let number = rest.parse::<DigitString>().map_err(|_| core::fmt::Error)?;
+                write!(f, "{}", self.with_number(number).with_ordinal(false))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Render for NumbersToWords<Digits> {
+    fn render(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.settings.digit_style {
+            DigitStyle::Single => self.single(f),
+            DigitStyle::Compressed => self.compressed(f),
+            DigitStyle::Grouped(size) => self.grouped(f, size),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{format, string::String};
+
+    use super::{DigitStyle, Digits};
+    use crate::{
+        numbers_to_words::NumbersToWords,
+        render_options::RenderOptions,
+        validation_errors::{InputError, ParseDigitsError},
+        validator::Validator,
+    };
+
+    fn read(digits: &str, style: DigitStyle) -> String {
+        let digits = digits.parse::<Digits>().unwrap();
+        format!("{}", NumbersToWords::new(digits).digit_style(style))
+    }
+
+    #[test]
+    fn displays_single_digits() {
+        assert_eq!(read("420", DigitStyle::Single), "four two zero");
+        assert_eq!(read("0123456789", DigitStyle::Single), "zero one two three four five six seven eight nine");
+        assert_eq!(read("0", DigitStyle::Single), "zero");
+        assert_eq!(read("007", DigitStyle::Single), "zero zero seven");
+    }
+
+    #[test]
+    fn displays_single_digits_by_default() {
+        let digits = "0207".parse::<Digits>().unwrap();
+        assert_eq!(format!("{}", NumbersToWords::new(digits)), "zero two zero seven");
+    }
+
+    #[test]
+    fn displays_compressed_digits() {
+        assert_eq!(read("77", DigitStyle::Compressed), "double seven");
+        assert_eq!(read("1777", DigitStyle::Compressed), "one triple seven");
+        assert_eq!(read("007", DigitStyle::Compressed), "double zero seven");
+        assert_eq!(read("4444", DigitStyle::Compressed), "double four double four");
+        assert_eq!(read("55555", DigitStyle::Compressed), "triple five double five");
+        assert_eq!(read("1111111", DigitStyle::Compressed), "triple one double one double one");
+        assert_eq!(read("1212", DigitStyle::Compressed), "one two one two");
+    }
+
+    #[test]
+    fn displays_grouped_digits() {
+        assert_eq!(read("4207", DigitStyle::Grouped(2)), "forty two, oh seven");
+        assert_eq!(read("0000", DigitStyle::Grouped(2)), "oh oh, oh oh");
+        assert_eq!(read("10", DigitStyle::Grouped(2)), "ten");
+        assert_eq!(read("12345", DigitStyle::Grouped(2)), "twelve, thirty four, five");
+        assert_eq!(read("020794", DigitStyle::Grouped(3)), "oh twenty, seven hundred and ninety four");
+        assert_eq!(read("0012", DigitStyle::Grouped(4)), "oh oh twelve");
+        assert_eq!(read("42", DigitStyle::Grouped(0)), "four, two");
+
+        let options = NumbersToWords::new("4207".parse::<Digits>().unwrap())
+            .digit_style(DigitStyle::Grouped(2))
+            .options(RenderOptions::AMERICAN);
+        assert_eq!(format!("{options}"), "forty-two, oh seven");
+    }
+
+    #[test]
+    fn parses_digits() {
+        assert_eq!("0042".parse::<Digits>().map(|digits| digits.len()), Ok(4));
+        assert_eq!("".parse::<Digits>(), Err(ParseDigitsError { position: 0 }));
+        assert_eq!("12-34".parse::<Digits>(), Err(ParseDigitsError { position: 2 }));
+        assert_eq!("-1".parse::<Digits>(), Err(ParseDigitsError { position: 0 }));
+    }
+
+    #[test]
+    fn validates_digits() {
+        let rules = NumbersToWords::<Digits>::rules().custom("eight digits", |digits| digits.len() == 8);
+
+        let actual = rules.validate("00123456").unwrap().digit_style(DigitStyle::Compressed);
+        assert_eq!(format!("{actual}"), "double zero one two three four five six");
+
+        let actual = rules.validate("0012345").err().unwrap();
+        assert_eq!(format!("{actual}"), "0012345 is not a valid number, it must be eight digits");
+
+        let actual = rules.validate("0012 3456");
+        assert!(matches!(actual, Err(InputError::ParseError { position: 4, .. })));
+    }
+}
@@ -0,0 +1,267 @@
+use crate::{
+    languages::Language,
+    numbers_to_words::NumbersToWords,
+    ordinal::write_ordinal_word,
+    render_options::{AndPlacement, RenderOptions},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct English;
+
+impl Language for English {
+    fn negative_word(&self) -> &'static str {
+        "minus"
+    }
+
+    fn write_cardinal(
+        words: &NumbersToWords<u128, Self>,
+        f: &mut core::fmt::Formatter<'_>,
+    ) -> core::fmt::Result {
+        words.render_words(f)
+    }
+}
+
+// Short and long scale names, largest last, up to the largest power of a thousand a u128 can hold
+const SCALES: [(u128, &str, &str); 12] = [
+    (1_000, "thousand", "thousand"),
+    (1_000_000, "million", "million"),
+    (1_000_000_000, "billion", "milliard"),
+    (1_000_000_000_000, "trillion", "billion"),
+    (1_000_000_000_000_000, "quadrillion", "billiard"),
+    (1_000_000_000_000_000_000, "quintillion", "trillion"),
+    (1_000_000_000_000_000_000_000, "sextillion", "trilliard"),
+    (1_000_000_000_000_000_000_000_000, "septillion", "quadrillion"),
+    (1_000_000_000_000_000_000_000_000_000, "octillion", "quadrilliard"),
+    (1_000_000_000_000_000_000_000_000_000_000, "nonillion", "quintillion"),
+    (1_000_000_000_000_000_000_000_000_000_000_000, "decillion", "quintilliard"),
+    (1_000_000_000_000_000_000_000_000_000_000_000_000, "undecillion", "sextillion"),
+];
+
+impl NumbersToWords<u128, English> {
+    fn part(&self, number: u128) -> Self {
+        self.with_number(number)
+    }
+
+    // A leading part of the number, which is never read as an ordinal
+    fn cardinal(&self, number: u128) -> Self {
+        self.part(number).with_ordinal(false)
+    }
+
+    fn word(&self, f: &mut core::fmt::Formatter<'_>, word: &str) -> core::fmt::Result {
+        if self.settings.ordinal {
+            return write_ordinal_word(f, word);
+        }
+
+        write!(f, "{}", word)
+    }
+
+    fn render(
+        &self,
+        f: &mut core::fmt::Formatter<'_>,
+        mod_val: u128,
+        delim_val: u128,
+        mag: &str,
+    ) -> core::fmt::Result {
+        let mod_mag = self.number % mod_val;
+        if mod_mag == 0 {
+            write!(f, "{} ", self.cardinal(self.number / mod_val))?;
+            return self.word(f, mag);
+        }
+
+        let mut delim = "";
+        if mod_mag < delim_val {
+            delim = self.settings.options.and();
+        }
+
+        write!(
+            f,
+            "{} {}{}",
+            self.cardinal(self.number - mod_mag),
+            delim,
+            self.part(mod_mag)
+        )
+    }
+
+    fn render_words(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.number {
+            0 => self.word(f, "zero"),
+            1 => self.word(f, "one"),
+            2 => self.word(f, "two"),
+            3 => self.word(f, "three"),
+            4 => self.word(f, "four"),
+            5 => self.word(f, "five"),
+            6 => self.word(f, "six"),
+            7 => self.word(f, "seven"),
+            8 => self.word(f, "eight"),
+            9 => self.word(f, "nine"),
+            10 => self.word(f, "ten"),
+            11 => self.word(f, "eleven"),
+            12 => self.word(f, "twelve"),
+            13 => self.word(f, "thirteen"),
+            14 => self.word(f, "fourteen"),
+            15 => self.word(f, "fifteen"),
+            16 => self.word(f, "sixteen"),
+            17 => self.word(f, "seventeen"),
+            18 => self.word(f, "eighteen"),
+            19 => self.word(f, "nineteen"),
+            20 => self.word(f, "twenty"),
+            30 => self.word(f, "thirty"),
+            40 => self.word(f, "forty"),
+            50 => self.word(f, "fifty"),
+            60 => self.word(f, "sixty"),
+            70 => self.word(f, "seventy"),
+            80 => self.word(f, "eighty"),
+            90 => self.word(f, "ninety"),
+            21..=99 => {
+                let mod_ten = self.number % 10;
+                write!(
+                    f,
+                    "{}{}{}",
+                    self.cardinal(self.number - mod_ten),
+                    self.settings.options.tens_separator(),
+                    self.part(mod_ten)
+                )
+            }
+            100..=999 => {
+                let mod_hundred = self.number % 100;
+                if mod_hundred == 0 {
+                    write!(f, "{} ", self.cardinal(self.number / 100))?;
+                    return self.word(f, "hundred");
+                }
+
+                write!(
+                    f,
+                    "{} {}{}",
+                    self.cardinal(self.number - mod_hundred),
+                    self.settings.options.and(),
+                    self.part(mod_hundred)
+                )
+            }
+            n => {
+                let index = SCALES
+                    .iter()
+                    .rposition(|(scale, _, _)| n >= *scale)
+                    .unwrap_or_default();
+
+                // "and" joins a remainder smaller than the next scale down,
+                // or smaller than a hundred when counting thousands
+                let (scale, short, long) = SCALES[index];
+                let mag = if self.settings.options.long_scale { long } else { short };
+                let delim_val = match (index, self.settings.options.and_placement) {
+                    (0, _) | (_, AndPlacement::Final) => 100,
+                    (_, AndPlacement::Remainder) => SCALES[index - 1].0,
+                };
+
+                self.render(f, scale, delim_val, mag)
+            }
+        }
+    }
+}
+
+const ONES: [&str; 20] = [
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten", "eleven",
+    "twelve", "thirteen", "fourteen", "fifteen", "sixteen", "seventeen", "eighteen", "nineteen",
+];
+
+const TENS: [&str; 10] = [
+    "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+fn write_word<W: core::fmt::Write>(out: &mut W, word: &str, ordinal: bool) -> core::fmt::Result {
+    if ordinal {
+        return write_ordinal_word(out, word);
+    }
+
+    out.write_str(word)
+}
+
+// A single group of three digits, "five hundred and ninety"
+fn write_group<W: core::fmt::Write>(
+    out: &mut W,
+    group: usize,
+    options: RenderOptions,
+    ordinal: bool,
+) -> core::fmt::Result {
+    let (hundreds, rest) = (group / 100, group % 100);
+    if hundreds > 0 {
+        out.write_str(ONES[hundreds])?;
+        out.write_char(' ')?;
+        write_word(out, "hundred", ordinal && rest == 0)?;
+        if rest == 0 {
+            return Ok(());
+        }
+
+        out.write_char(' ')?;
+        out.write_str(options.and())?;
+    }
+
+    match rest {
+        0..=19 => write_word(out, ONES[rest], ordinal),
+        _ if rest % 10 == 0 => write_word(out, TENS[rest / 10], ordinal),
+        _ => {
+            out.write_str(TENS[rest / 10])?;
+            out.write_str(options.tens_separator())?;
+            write_word(out, ONES[rest % 10], ordinal)
+        }
+    }
+}
+
+// The same words as the Display impl, written in a single pass over the groups of three
+// digits without recursing or allocating
+pub(crate) fn write_words<W: core::fmt::Write>(
+    out: &mut W,
+    number: u128,
+    options: RenderOptions,
+    ordinal: bool,
+) -> core::fmt::Result {
+    if number == 0 {
+        return write_word(out, ONES[0], ordinal);
+    }
+
+    // u128::MAX has thirteen groups, least significant first
+    let mut groups = [0_usize; 13];
+    let mut count = 0;
+    let mut rest = number;
+    while rest > 0 {
+        groups[count] = (rest % 1000) as usize;
+        rest /= 1000;
+        count += 1;
+    }
+
+    let lowest = groups.iter().position(|group| *group != 0).unwrap_or_default();
+    let mut previous: Option<usize> = None;
+    for index in (0..count).rev() {
+        let group = groups[index];
+        if group == 0 {
+            continue;
+        }
+
+        // "and" joins a remainder smaller than the next scale down,
+        // or smaller than a hundred when counting thousands
+        if let Some(previous) = previous {
+            out.write_char(' ')?;
+
+            let remainder = number % SCALES[previous - 1].0;
+            let delim_val = match (previous, options.and_placement) {
+                (1, _) | (_, AndPlacement::Final) => 100,
+                (_, AndPlacement::Remainder) => SCALES[previous - 2].0,
+            };
+
+            if remainder < delim_val {
+                out.write_str(options.and())?;
+            }
+        }
+
+        write_group(out, group, options, ordinal && index == 0)?;
+        if index > 0 {
+            let (_, short, long) = SCALES[index - 1];
+            out.write_char(' ')?;
+            write_word(out, if options.long_scale { long } else { short }, ordinal && index == lowest)?;
+        }
+
+        previous = Some(index);
+    }
+
+    Ok(())
+}
+
@@ -0,0 +1,215 @@
+use alloc::vec;
+
+use crate::{
+    languages::{Gender, Language, PluralCategory},
+    numbers_to_words::NumbersToWords,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct French;
+
+const UNITS: [&str; 20] = [
+    "zéro", "un", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf", "dix", "onze",
+    "douze", "treize", "quatorze", "quinze", "seize", "dix-sept", "dix-huit", "dix-neuf",
+];
+
+const TENS: [&str; 7] = ["", "", "vingt", "trente", "quarante", "cinquante", "soixante"];
+
+// Long scale, each name a thousand times the one before, starting at 10^6
+const SCALES: [&str; 11] = [
+    "million",
+    "milliard",
+    "billion",
+    "billiard",
+    "trillion",
+    "trilliard",
+    "quadrillion",
+    "quadrilliard",
+    "quintillion",
+    "quintilliard",
+    "sextillion",
+];
+
+fn unit(n: u128, feminine: bool) -> &'static str {
+    match n {
+        1 if feminine => "une",
+        n => UNITS[n as usize],
+    }
+}
+
+// "quatre-vingts" and "deux cents" only take their plural s when nothing follows them
+fn below_hundred(f: &mut core::fmt::Formatter<'_>, n: u128, feminine: bool, last: bool) -> core::fmt::Result {
+    let (tens, units) = (n / 10, n % 10);
+    match n {
+        0..=19 => write!(f, "{}", unit(n, feminine)),
+        20..=69 if units == 0 => write!(f, "{}", TENS[tens as usize]),
+        20..=69 if units == 1 => write!(f, "{} et {}", TENS[tens as usize], unit(1, feminine)),
+        20..=69 => write!(f, "{}-{}", TENS[tens as usize], UNITS[units as usize]),
+        // Seventies count on from sixty, "soixante-dix" and "soixante et onze"
+        71 => write!(f, "soixante et onze"),
+        70..=79 => write!(f, "soixante-{}", UNITS[(n - 60) as usize]),
+        80 if last => write!(f, "quatre-vingts"),
+        80 => write!(f, "quatre-vingt"),
+        _ => write!(f, "quatre-vingt-{}", unit(n - 80, feminine)),
+    }
+}
+
+fn below_thousand(f: &mut core::fmt::Formatter<'_>, n: u128, feminine: bool, last: bool) -> core::fmt::Result {
+    let (hundreds, rest) = (n / 100, n % 100);
+    match hundreds {
+        0 => return below_hundred(f, rest, feminine, last),
+        1 => write!(f, "cent")?,
+        _ if rest == 0 && last => return write!(f, "{} cents", UNITS[hundreds as usize]),
+        _ => write!(f, "{} cent", UNITS[hundreds as usize])?,
+    }
+
+    if rest > 0 {
+        write!(f, " ")?;
+        below_hundred(f, rest, feminine, last)?;
+    }
+
+    Ok(())
+}
+
+impl Language for French {
+    fn negative_word(&self) -> &'static str {
+        "moins"
+    }
+
+    // Zero counts as one, and whole millions take "de" before the noun
+    fn plural_category(&self, count: u128) -> PluralCategory {
+        match count {
+            0 | 1 => PluralCategory::One,
+            _ if count.is_multiple_of(1_000_000) => PluralCategory::Many,
+            _ => PluralCategory::Other,
+        }
+    }
+
+    fn write_cardinal(
+        words: &NumbersToWords<u128, Self>,
+        f: &mut core::fmt::Formatter<'_>,
+    ) -> core::fmt::Result {
+        if words.number == 0 {
+            return write!(f, "{}", UNITS[0]);
+        }
+
+        let mut groups = vec![];
+        let mut rest = words.number;
+        while rest > 0 {
+            groups.push(rest % 1000);
+            rest /= 1000;
+        }
+
+        let feminine = words.settings.gender == Gender::Feminine;
+        let mut first = true;
+        for (index, group) in groups.iter().copied().enumerate().rev() {
+            if group == 0 {
+                continue;
+            }
+
+            if !first {
+                write!(f, " ")?;
+            }
+            first = false;
+
+            match index {
+                0 => below_thousand(f, group, feminine, true)?,
+                // "mille" never takes a number one or a plural s
+                1 if group == 1 => write!(f, "mille")?,
+                1 => {
+                    below_thousand(f, group, false, false)?;
+                    write!(f, " mille")?;
+                }
+                _ => {
+                    below_thousand(f, group, false, true)?;
+                    let plural = if group > 1 { "s" } else { "" };
+                    write!(f, " {}{}", SCALES[index - 2], plural)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{format, string::String};
+
+    use super::French;
+    use crate::{languages::Gender, numbers_to_words::NumbersToWords};
+
+    fn words(number: i64) -> String {
+        format!("{}", NumbersToWords::new(number).language(French))
+    }
+
+    #[test]
+    fn displays_below_one_hundred() {
+        let expected = [
+            "zéro", "un", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf",
+            "dix", "onze", "douze", "treize", "quatorze", "quinze", "seize", "dix-sept", "dix-huit", "dix-neuf",
+            "vingt", "vingt et un", "vingt-deux", "vingt-trois", "vingt-quatre", "vingt-cinq", "vingt-six", "vingt-sept", "vingt-huit", "vingt-neuf",
+            "trente", "trente et un", "trente-deux", "trente-trois", "trente-quatre", "trente-cinq", "trente-six", "trente-sept", "trente-huit", "trente-neuf",
+            "quarante", "quarante et un", "quarante-deux", "quarante-trois", "quarante-quatre", "quarante-cinq", "quarante-six", "quarante-sept", "quarante-huit", "quarante-neuf",
+            "cinquante", "cinquante et un", "cinquante-deux", "cinquante-trois", "cinquante-quatre", "cinquante-cinq", "cinquante-six", "cinquante-sept", "cinquante-huit", "cinquante-neuf",
+            "soixante", "soixante et un", "soixante-deux", "soixante-trois", "soixante-quatre", "soixante-cinq", "soixante-six", "soixante-sept", "soixante-huit", "soixante-neuf",
+            "soixante-dix", "soixante et onze", "soixante-douze", "soixante-treize", "soixante-quatorze", "soixante-quinze", "soixante-seize", "soixante-dix-sept", "soixante-dix-huit", "soixante-dix-neuf",
+            "quatre-vingts", "quatre-vingt-un", "quatre-vingt-deux", "quatre-vingt-trois", "quatre-vingt-quatre", "quatre-vingt-cinq", "quatre-vingt-six", "quatre-vingt-sept", "quatre-vingt-huit", "quatre-vingt-neuf",
+            "quatre-vingt-dix", "quatre-vingt-onze", "quatre-vingt-douze", "quatre-vingt-treize", "quatre-vingt-quatorze", "quatre-vingt-quinze", "quatre-vingt-seize", "quatre-vingt-dix-sept", "quatre-vingt-dix-huit", "quatre-vingt-dix-neuf",
+        ];
+
+        expected
+            .iter()
+            .enumerate()
+            .for_each(|(n, word)| assert_eq!(words(n as i64), *word));
+    }
+
+    #[test]
+    fn displays_hundreds() {
+        assert_eq!(words(100), "cent");
+        assert_eq!(words(101), "cent un");
+        assert_eq!(words(180), "cent quatre-vingts");
+        assert_eq!(words(200), "deux cents");
+        assert_eq!(words(201), "deux cent un");
+        assert_eq!(words(271), "deux cent soixante et onze");
+        assert_eq!(words(999), "neuf cent quatre-vingt-dix-neuf");
+    }
+
+    #[test]
+    fn displays_thousands() {
+        assert_eq!(words(1_000), "mille");
+        assert_eq!(words(1_001), "mille un");
+        assert_eq!(words(2_000), "deux mille");
+        assert_eq!(words(21_000), "vingt et un mille");
+        assert_eq!(words(80_000), "quatre-vingt mille");
+        assert_eq!(words(200_000), "deux cent mille");
+        assert_eq!(words(999_999), "neuf cent quatre-vingt-dix-neuf mille neuf cent quatre-vingt-dix-neuf");
+    }
+
+    #[test]
+    fn displays_long_scale() {
+        assert_eq!(words(1_000_000), "un million");
+        assert_eq!(words(2_000_000), "deux millions");
+        assert_eq!(words(80_000_000), "quatre-vingts millions");
+        assert_eq!(words(200_000_000), "deux cents millions");
+        assert_eq!(words(1_000_000_000), "un milliard");
+        assert_eq!(words(3_000_200_001), "trois milliards deux cent mille un");
+        assert_eq!(words(1_000_000_000_000), "un billion");
+        assert_eq!(words(1_000_000_000_000_000), "un billiard");
+        assert_eq!(words(2_000_000_000_000_000_000), "deux trillions");
+
+        let actual = NumbersToWords::new(10_u128.pow(36)).language(French);
+        assert_eq!(format!("{actual}"), "un sextillion");
+    }
+
+    #[test]
+    fn displays_feminine_and_negative() {
+        let feminine = |n: i64| format!("{}", NumbersToWords::new(n).language(French).gender(Gender::Feminine));
+
+        assert_eq!(feminine(1), "une");
+        assert_eq!(feminine(21), "vingt et une");
+        assert_eq!(feminine(81), "quatre-vingt-une");
+        assert_eq!(feminine(1_000_001), "un million une");
+        assert_eq!(words(-42), "moins quarante-deux");
+    }
+}
@@ -0,0 +1,202 @@
+use alloc::vec;
+
+use crate::{
+    languages::{Gender, Language},
+    numbers_to_words::NumbersToWords,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct German;
+
+const UNITS: [&str; 20] = [
+    "null", "eins", "zwei", "drei", "vier", "fünf", "sechs", "sieben", "acht", "neun", "zehn",
+    "elf", "zwölf", "dreizehn", "vierzehn", "fünfzehn", "sechzehn", "siebzehn", "achtzehn",
+    "neunzehn",
+];
+
+const TENS: [&str; 10] = [
+    "", "", "zwanzig", "dreißig", "vierzig", "fünfzig", "sechzig", "siebzig", "achtzig", "neunzig",
+];
+
+// Long scale nouns, each a thousand times the one before, starting at 10^6
+const SCALES: [(&str, &str); 11] = [
+    ("Million", "Millionen"),
+    ("Milliarde", "Milliarden"),
+    ("Billion", "Billionen"),
+    ("Billiarde", "Billiarden"),
+    ("Trillion", "Trillionen"),
+    ("Trilliarde", "Trilliarden"),
+    ("Quadrillion", "Quadrillionen"),
+    ("Quadrilliarde", "Quadrilliarden"),
+    ("Quintillion", "Quintillionen"),
+    ("Quintilliarde", "Quintilliarden"),
+    ("Sextillion", "Sextillionen"),
+];
+
+// A one standing on its own is "eins" at the end of the number, "ein" before "hundert" or
+// "tausend" and "eine" before the feminine scale nouns. Within "einundzwanzig" it is always "ein"
+fn below_hundred(f: &mut core::fmt::Formatter<'_>, n: u128, one: &str) -> core::fmt::Result {
+    let (tens, units) = (n / 10, n % 10);
+    match n {
+        1 => write!(f, "{}", one),
+        0..=19 => write!(f, "{}", UNITS[n as usize]),
+        _ if units == 0 => write!(f, "{}", TENS[tens as usize]),
+        // The unit comes before the ten, "einundzwanzig" is one and twenty
+        _ => {
+            below_hundred(f, units, "ein")?;
+            write!(f, "und{}", TENS[tens as usize])
+        }
+    }
+}
+
+fn below_thousand(f: &mut core::fmt::Formatter<'_>, n: u128, one: &str) -> core::fmt::Result {
+    let (hundreds, rest) = (n / 100, n % 100);
+    if hundreds > 0 {
+        below_hundred(f, hundreds, "ein")?;
+        write!(f, "hundert")?;
+    }
+
+    if rest > 0 || hundreds == 0 {
+        below_hundred(f, rest, one)?;
+    }
+
+    Ok(())
+}
+
+// Everything below a million is written as a single word
+fn below_million(f: &mut core::fmt::Formatter<'_>, n: u128, one: &str) -> core::fmt::Result {
+    let (thousands, rest) = (n / 1000, n % 1000);
+    if thousands > 0 {
+        below_thousand(f, thousands, "ein")?;
+        write!(f, "tausend")?;
+    }
+
+    if rest > 0 || thousands == 0 {
+        below_thousand(f, rest, one)?;
+    }
+
+    Ok(())
+}
+
+impl Language for German {
+    fn negative_word(&self) -> &'static str {
+        "minus"
+    }
+
+    fn write_cardinal(
+        words: &NumbersToWords<u128, Self>,
+        f: &mut core::fmt::Formatter<'_>,
+    ) -> core::fmt::Result {
+        let mut groups = vec![];
+        let mut rest = words.number / 1_000_000;
+        while rest > 0 {
+            groups.push(rest % 1000);
+            rest /= 1000;
+        }
+
+        let mut first = true;
+        for (index, group) in groups.iter().copied().enumerate().rev() {
+            if group == 0 {
+                continue;
+            }
+
+            if !first {
+                write!(f, " ")?;
+            }
+            first = false;
+
+            // The scale words are feminine nouns, so one of them is "eine", as is a count
+            // ending in one, "einhunderteine Millionen"
+            let (singular, plural) = SCALES[index];
+            below_thousand(f, group, "eine")?;
+            write!(f, " {}", if group == 1 { singular } else { plural })?;
+        }
+
+        let rest = words.number % 1_000_000;
+        if rest > 0 || first {
+            if !first {
+                write!(f, " ")?;
+            }
+
+            // Before a noun one agrees with it, "ein Ordner" and "eine Datei"
+            let one = match (words.settings.before_noun, words.settings.gender) {
+                (false, _) => "eins",
+                (true, Gender::Feminine) => "eine",
+                (true, _) => "ein",
+            };
+            below_million(f, rest, one)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{format, string::String};
+
+    use super::German;
+    use crate::numbers_to_words::NumbersToWords;
+
+    fn words(number: i64) -> String {
+        format!("{}", NumbersToWords::new(number).language(German))
+    }
+
+    #[test]
+    fn displays_below_one_hundred() {
+        let expected = [
+            "null", "eins", "zwei", "drei", "vier", "fünf", "sechs", "sieben", "acht", "neun",
+            "zehn", "elf", "zwölf", "dreizehn", "vierzehn", "fünfzehn", "sechzehn", "siebzehn", "achtzehn", "neunzehn",
+            "zwanzig", "einundzwanzig", "zweiundzwanzig", "dreiundzwanzig", "vierundzwanzig", "fünfundzwanzig", "sechsundzwanzig", "siebenundzwanzig", "achtundzwanzig", "neunundzwanzig",
+            "dreißig", "einunddreißig", "zweiunddreißig", "dreiunddreißig", "vierunddreißig", "fünfunddreißig", "sechsunddreißig", "siebenunddreißig", "achtunddreißig", "neununddreißig",
+            "vierzig", "einundvierzig", "zweiundvierzig", "dreiundvierzig", "vierundvierzig", "fünfundvierzig", "sechsundvierzig", "siebenundvierzig", "achtundvierzig", "neunundvierzig",
+            "fünfzig", "einundfünfzig", "zweiundfünfzig", "dreiundfünfzig", "vierundfünfzig", "fünfundfünfzig", "sechsundfünfzig", "siebenundfünfzig", "achtundfünfzig", "neunundfünfzig",
+            "sechzig", "einundsechzig", "zweiundsechzig", "dreiundsechzig", "vierundsechzig", "fünfundsechzig", "sechsundsechzig", "siebenundsechzig", "achtundsechzig", "neunundsechzig",
+            "siebzig", "einundsiebzig", "zweiundsiebzig", "dreiundsiebzig", "vierundsiebzig", "fünfundsiebzig", "sechsundsiebzig", "siebenundsiebzig", "achtundsiebzig", "neunundsiebzig",
+            "achtzig", "einundachtzig", "zweiundachtzig", "dreiundachtzig", "vierundachtzig", "fünfundachtzig", "sechsundachtzig", "siebenundachtzig", "achtundachtzig", "neunundachtzig",
+            "neunzig", "einundneunzig", "zweiundneunzig", "dreiundneunzig", "vierundneunzig", "fünfundneunzig", "sechsundneunzig", "siebenundneunzig", "achtundneunzig", "neunundneunzig",
+        ];
+
+        expected
+            .iter()
+            .enumerate()
+            .for_each(|(n, word)| assert_eq!(words(n as i64), *word));
+    }
+
+    #[test]
+    fn displays_hundreds_and_thousands() {
+        assert_eq!(words(100), "einhundert");
+        assert_eq!(words(101), "einhunderteins");
+        assert_eq!(words(121), "einhunderteinundzwanzig");
+        assert_eq!(words(999), "neunhundertneunundneunzig");
+        assert_eq!(words(1_000), "eintausend");
+        assert_eq!(words(1_001), "eintausendeins");
+        assert_eq!(words(21_000), "einundzwanzigtausend");
+        assert_eq!(words(123_456), "einhundertdreiundzwanzigtausendvierhundertsechsundfünfzig");
+    }
+
+    #[test]
+    fn displays_long_scale() {
+        assert_eq!(words(1_000_000), "eine Million");
+        assert_eq!(words(2_000_000), "zwei Millionen");
+        assert_eq!(words(1_200_000), "eine Million zweihunderttausend");
+        assert_eq!(words(1_000_000_001), "eine Milliarde eins");
+        assert_eq!(words(21_000_000_000), "einundzwanzig Milliarden");
+        assert_eq!(words(1_000_000_000_000), "eine Billion");
+        assert_eq!(words(5_000_001_000_000), "fünf Billionen eine Million");
+        assert_eq!(words(1_000_000_000_000_000), "eine Billiarde");
+        assert_eq!(words(101_000_000), "einhunderteine Millionen");
+        assert_eq!(words(201_000_000_000), "zweihunderteine Milliarden");
+        assert_eq!(words(111_000_000), "einhundertelf Millionen");
+        assert_eq!(words(101_000), "einhunderteintausend");
+
+        let actual = NumbersToWords::new(10_u128.pow(36)).language(German);
+        assert_eq!(format!("{actual}"), "eine Sextillion");
+    }
+
+    #[test]
+    fn displays_negatives() {
+        assert_eq!(words(-21), "minus einundzwanzig");
+        assert_eq!(words(-1), "minus eins");
+    }
+}
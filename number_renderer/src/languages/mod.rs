@@ -0,0 +1,65 @@
+use crate::numbers_to_words::NumbersToWords;
+
+mod english;
+mod french;
+mod german;
+mod polish;
+mod russian;
+mod spanish;
+
+pub use english::English;
+pub(crate) use english::write_words;
+pub use french::French;
+pub use german::German;
+pub use polish::Polish;
+pub use russian::Russian;
+pub use spanish::Spanish;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Gender {
+    Masculine,
+    Feminine,
+    Neuter,
+}
+
+// The grammatical case of the counted noun, which inflected languages carry through every word
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Case {
+    #[default]
+    Nominative,
+    Genitive,
+    Dative,
+    Accusative,
+    Instrumental,
+    // The prepositional case in Russian
+    Locative,
+}
+
+// The CLDR plural categories, which decide the form a counted noun takes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PluralCategory {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+pub trait Language: Copy {
+    fn negative_word(&self) -> &'static str;
+
+    // The plural category of a count, singular for one and plural otherwise unless the language says more
+    fn plural_category(&self, count: u128) -> PluralCategory {
+        match count {
+            1 => PluralCategory::One,
+            _ => PluralCategory::Other,
+        }
+    }
+
+    // Writes the words for a number that has already had its sign removed
+    fn write_cardinal(
+        words: &NumbersToWords<u128, Self>,
+        f: &mut core::fmt::Formatter<'_>,
+    ) -> core::fmt::Result;
+}
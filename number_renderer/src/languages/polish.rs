@@ -0,0 +1,337 @@
+use alloc::vec;
+
+use crate::{
+    languages::{Case, Gender, Language, PluralCategory},
+    numbers_to_words::NumbersToWords,
+};
+
+// Masculine is taken to mean things rather than people, "dwa" rather than "dwaj"
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Polish;
+
+// A word in the nominative, genitive, dative, accusative, instrumental and locative
+type Forms = [&'static str; 6];
+
+const ZERO: Forms = ["zero", "zera", "zeru", "zero", "zerem", "zerze"];
+
+// One agrees with the noun in gender as well as case, but only when it stands alone
+const ONE_MASCULINE: Forms = ["jeden", "jednego", "jednemu", "jeden", "jednym", "jednym"];
+const ONE_FEMININE: Forms = ["jedna", "jednej", "jednej", "jedną", "jedną", "jednej"];
+const ONE_NEUTER: Forms = ["jedno", "jednego", "jednemu", "jedno", "jednym", "jednym"];
+
+// Two is "dwie" for feminine nouns and "dwa" for the rest
+const TWO_FEMININE: Forms = ["dwie", "dwóch", "dwóm", "dwie", "dwiema", "dwóch"];
+
+const UNITS: [Forms; 20] = [
+    ZERO,
+    ONE_MASCULINE,
+    ["dwa", "dwóch", "dwóm", "dwa", "dwoma", "dwóch"],
+    ["trzy", "trzech", "trzem", "trzy", "trzema", "trzech"],
+    ["cztery", "czterech", "czterem", "cztery", "czterema", "czterech"],
+    ["pięć", "pięciu", "pięciu", "pięć", "pięcioma", "pięciu"],
+    ["sześć", "sześciu", "sześciu", "sześć", "sześcioma", "sześciu"],
+    ["siedem", "siedmiu", "siedmiu", "siedem", "siedmioma", "siedmiu"],
+    ["osiem", "ośmiu", "ośmiu", "osiem", "ośmioma", "ośmiu"],
+    ["dziewięć", "dziewięciu", "dziewięciu", "dziewięć", "dziewięcioma", "dziewięciu"],
+    ["dziesięć", "dziesięciu", "dziesięciu", "dziesięć", "dziesięcioma", "dziesięciu"],
+    ["jedenaście", "jedenastu", "jedenastu", "jedenaście", "jedenastoma", "jedenastu"],
+    ["dwanaście", "dwunastu", "dwunastu", "dwanaście", "dwunastoma", "dwunastu"],
+    ["trzynaście", "trzynastu", "trzynastu", "trzynaście", "trzynastoma", "trzynastu"],
+    ["czternaście", "czternastu", "czternastu", "czternaście", "czternastoma", "czternastu"],
+    ["piętnaście", "piętnastu", "piętnastu", "piętnaście", "piętnastoma", "piętnastu"],
+    ["szesnaście", "szesnastu", "szesnastu", "szesnaście", "szesnastoma", "szesnastu"],
+    ["siedemnaście", "siedemnastu", "siedemnastu", "siedemnaście", "siedemnastoma", "siedemnastu"],
+    ["osiemnaście", "osiemnastu", "osiemnastu", "osiemnaście", "osiemnastoma", "osiemnastu"],
+    ["dziewiętnaście", "dziewiętnastu", "dziewiętnastu", "dziewiętnaście", "dziewiętnastoma", "dziewiętnastu"],
+];
+
+const TENS: [Forms; 10] = [
+    [""; 6],
+    [""; 6],
+    ["dwadzieścia", "dwudziestu", "dwudziestu", "dwadzieścia", "dwudziestoma", "dwudziestu"],
+    ["trzydzieści", "trzydziestu", "trzydziestu", "trzydzieści", "trzydziestoma", "trzydziestu"],
+    ["czterdzieści", "czterdziestu", "czterdziestu", "czterdzieści", "czterdziestoma", "czterdziestu"],
+    ["pięćdziesiąt", "pięćdziesięciu", "pięćdziesięciu", "pięćdziesiąt", "pięćdziesięcioma", "pięćdziesięciu"],
+    ["sześćdziesiąt", "sześćdziesięciu", "sześćdziesięciu", "sześćdziesiąt", "sześćdziesięcioma", "sześćdziesięciu"],
+    ["siedemdziesiąt", "siedemdziesięciu", "siedemdziesięciu", "siedemdziesiąt", "siedemdziesięcioma", "siedemdziesięciu"],
+    ["osiemdziesiąt", "osiemdziesięciu", "osiemdziesięciu", "osiemdziesiąt", "osiemdziesięcioma", "osiemdziesięciu"],
+    ["dziewięćdziesiąt", "dziewięćdziesięciu", "dziewięćdziesięciu", "dziewięćdziesiąt", "dziewięćdziesięcioma", "dziewięćdziesięciu"],
+];
+
+const HUNDREDS: [Forms; 10] = [
+    [""; 6],
+    ["sto", "stu", "stu", "sto", "stoma", "stu"],
+    ["dwieście", "dwustu", "dwustu", "dwieście", "dwustoma", "dwustu"],
+    ["trzysta", "trzystu", "trzystu", "trzysta", "trzystoma", "trzystu"],
+    ["czterysta", "czterystu", "czterystu", "czterysta", "czterystoma", "czterystu"],
+    ["pięćset", "pięciuset", "pięciuset", "pięćset", "pięciuset", "pięciuset"],
+    ["sześćset", "sześciuset", "sześciuset", "sześćset", "sześciuset", "sześciuset"],
+    ["siedemset", "siedmiuset", "siedmiuset", "siedemset", "siedmiuset", "siedmiuset"],
+    ["osiemset", "ośmiuset", "ośmiuset", "osiemset", "ośmiuset", "ośmiuset"],
+    ["dziewięćset", "dziewięciuset", "dziewięciuset", "dziewięćset", "dziewięciuset", "dziewięciuset"],
+];
+
+// Thousand is a masculine noun, singular forms then plural
+const THOUSAND: (Forms, Forms) = (
+    ["tysiąc", "tysiąca", "tysiącowi", "tysiąc", "tysiącem", "tysiącu"],
+    ["tysiące", "tysięcy", "tysiącom", "tysiące", "tysiącami", "tysiącach"],
+);
+
+// Long scale masculine nouns, each a thousand times the one before, starting at 10^6
+const SCALES: [&str; 11] = [
+    "milion",
+    "miliard",
+    "bilion",
+    "biliard",
+    "trylion",
+    "tryliard",
+    "kwadrylion",
+    "kwadryliard",
+    "kwintylion",
+    "kwintyliard",
+    "sekstylion",
+];
+
+// The locative singular is "milionie" but "miliardzie", so it is left to write_scale
+const SINGULAR_ENDINGS: Forms = ["", "a", "owi", "", "em", ""];
+const PLURAL_ENDINGS: Forms = ["y", "ów", "om", "y", "ami", "ach"];
+
+fn unit(n: u128, gender: Gender, case: Case) -> &'static str {
+    let forms = match (n, gender) {
+        (1, Gender::Masculine) => ONE_MASCULINE,
+        (1, Gender::Feminine) => ONE_FEMININE,
+        (1, Gender::Neuter) => ONE_NEUTER,
+        (2, Gender::Feminine) => TWO_FEMININE,
+        _ => UNITS[n as usize],
+    };
+
+    forms[case as usize]
+}
+
+// Every word of a compound number takes the case, except a trailing one, which stays
+// "jeden" whatever follows, as in "dwudziestu jeden"
+fn below_thousand(f: &mut core::fmt::Formatter<'_>, n: u128, gender: Gender, case: Case) -> core::fmt::Result {
+    let (hundreds, rest) = (n / 100, n % 100);
+    let (tens, units) = (rest / 10, rest % 10);
+
+    let one = |n| match n {
+        1 => "jeden",
+        _ => unit(n, gender, case),
+    };
+
+    let hundred = (hundreds > 0).then(|| HUNDREDS[hundreds as usize][case as usize]);
+    let (ten, unit) = match rest {
+        0 => (None, None),
+        1..=19 => (None, Some(one(rest))),
+        _ => (Some(TENS[tens as usize][case as usize]), (units > 0).then(|| one(units))),
+    };
+
+    let mut separator = "";
+    for word in [hundred, ten, unit].into_iter().flatten() {
+        write!(f, "{}{}", separator, word)?;
+        separator = " ";
+    }
+
+    Ok(())
+}
+
+// After one the noun is singular, after two to four it is nominative plural and after
+// five upwards genitive plural, though only in the nominative and accusative
+fn counted(category: PluralCategory, case: Case) -> (bool, Case) {
+    match (category, case) {
+        (PluralCategory::One, case) => (false, case),
+        (PluralCategory::Few, Case::Nominative | Case::Accusative) => (true, Case::Nominative),
+        (_, Case::Nominative | Case::Accusative) => (true, Case::Genitive),
+        (_, case) => (true, case),
+    }
+}
+
+fn write_scale(f: &mut core::fmt::Formatter<'_>, index: usize, plural: bool, case: Case) -> core::fmt::Result {
+    if index == 1 {
+        let forms = if plural { THOUSAND.1 } else { THOUSAND.0 };
+        return write!(f, "{}", forms[case as usize]);
+    }
+
+    let stem = SCALES[index - 2];
+    match (plural, case) {
+        (false, Case::Locative) if stem.ends_with('d') => write!(f, "{}zie", stem),
+        (false, Case::Locative) => write!(f, "{}ie", stem),
+        (false, case) => write!(f, "{}{}", stem, SINGULAR_ENDINGS[case as usize]),
+        (true, case) => write!(f, "{}{}", stem, PLURAL_ENDINGS[case as usize]),
+    }
+}
+
+impl Language for Polish {
+    fn negative_word(&self) -> &'static str {
+        "minus"
+    }
+
+    fn plural_category(&self, count: u128) -> PluralCategory {
+        match (count, count % 10, count % 100) {
+            (1, _, _) => PluralCategory::One,
+            (_, 2..=4, rest) if !(12..=14).contains(&rest) => PluralCategory::Few,
+            _ => PluralCategory::Many,
+        }
+    }
+
+    fn write_cardinal(
+        words: &NumbersToWords<u128, Self>,
+        f: &mut core::fmt::Formatter<'_>,
+    ) -> core::fmt::Result {
+        if words.number == 0 {
+            return write!(f, "{}", ZERO[words.settings.case as usize]);
+        }
+
+        // One on its own agrees with the noun, which a trailing one in a compound never does
+        if words.number == 1 {
+            return write!(f, "{}", unit(1, words.settings.gender, words.settings.case));
+        }
+
+        let mut groups = vec![];
+        let mut rest = words.number;
+        while rest > 0 {
+            groups.push(rest % 1000);
+            rest /= 1000;
+        }
+
+        let mut first = true;
+        for (index, group) in groups.iter().copied().enumerate().rev() {
+            if group == 0 {
+                continue;
+            }
+
+            if !first {
+                write!(f, " ")?;
+            }
+            first = false;
+
+            if index == 0 {
+                below_thousand(f, group, words.settings.gender, words.settings.case)?;
+                continue;
+            }
+
+            // A single thousand or million is just "tysiąc" or "milion"
+            if group != 1 {
+                below_thousand(f, group, Gender::Masculine, words.settings.case)?;
+                write!(f, " ")?;
+            }
+
+            let (plural, case) = counted(Polish.plural_category(group), words.settings.case);
+            write_scale(f, index, plural, case)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{format, string::String};
+
+    use super::Polish;
+    use crate::{
+        languages::{Case, Gender, Language, PluralCategory},
+        numbers_to_words::NumbersToWords,
+    };
+
+    fn words(number: i64) -> String {
+        format!("{}", NumbersToWords::new(number).language(Polish))
+    }
+
+    fn declined(number: i64, gender: Gender, case: Case) -> String {
+        format!("{}", NumbersToWords::new(number).language(Polish).gender(gender).case(case))
+    }
+
+    #[test]
+    fn displays_below_one_thousand() {
+        assert_eq!(words(0), "zero");
+        assert_eq!(words(1), "jeden");
+        assert_eq!(words(12), "dwanaście");
+        assert_eq!(words(21), "dwadzieścia jeden");
+        assert_eq!(words(99), "dziewięćdziesiąt dziewięć");
+        assert_eq!(words(100), "sto");
+        assert_eq!(words(101), "sto jeden");
+        assert_eq!(words(256), "dwieście pięćdziesiąt sześć");
+        assert_eq!(words(999), "dziewięćset dziewięćdziesiąt dziewięć");
+    }
+
+    #[test]
+    fn displays_scale_agreement() {
+        assert_eq!(words(1_000), "tysiąc");
+        assert_eq!(words(2_000), "dwa tysiące");
+        assert_eq!(words(4_000), "cztery tysiące");
+        assert_eq!(words(5_000), "pięć tysięcy");
+        assert_eq!(words(12_000), "dwanaście tysięcy");
+        assert_eq!(words(21_000), "dwadzieścia jeden tysięcy");
+        assert_eq!(words(22_000), "dwadzieścia dwa tysiące");
+        assert_eq!(words(1_001), "tysiąc jeden");
+        assert_eq!(words(1_000_000), "milion");
+        assert_eq!(words(3_000_000), "trzy miliony");
+        assert_eq!(words(5_000_000), "pięć milionów");
+        assert_eq!(words(2_000_000_000), "dwa miliardy");
+        assert_eq!(words(7_000_000_000_000), "siedem bilionów");
+        assert_eq!(words(2_500_000), "dwa miliony pięćset tysięcy");
+
+        let actual = NumbersToWords::new(10_u128.pow(36)).language(Polish);
+        assert_eq!(format!("{actual}"), "sekstylion");
+    }
+
+    #[test]
+    fn displays_gender() {
+        assert_eq!(declined(1, Gender::Feminine, Case::Nominative), "jedna");
+        assert_eq!(declined(1, Gender::Neuter, Case::Nominative), "jedno");
+        assert_eq!(declined(2, Gender::Feminine, Case::Nominative), "dwie");
+        assert_eq!(declined(22, Gender::Feminine, Case::Nominative), "dwadzieścia dwie");
+        assert_eq!(declined(21, Gender::Feminine, Case::Nominative), "dwadzieścia jeden");
+        assert_eq!(declined(2_000, Gender::Feminine, Case::Nominative), "dwa tysiące");
+    }
+
+    #[test]
+    fn displays_cases() {
+        let masculine = |number, case| declined(number, Gender::Masculine, case);
+
+        assert_eq!(masculine(2_000, Case::Genitive), "dwóch tysięcy");
+        assert_eq!(masculine(2_000, Case::Dative), "dwóm tysiącom");
+        assert_eq!(masculine(2_000, Case::Accusative), "dwa tysiące");
+        assert_eq!(masculine(2_000, Case::Instrumental), "dwoma tysiącami");
+        assert_eq!(masculine(2_000, Case::Locative), "dwóch tysiącach");
+        assert_eq!(masculine(5_000, Case::Genitive), "pięciu tysięcy");
+        assert_eq!(masculine(5_000, Case::Instrumental), "pięcioma tysiącami");
+        assert_eq!(masculine(1_000, Case::Genitive), "tysiąca");
+        assert_eq!(masculine(1_000_000, Case::Locative), "milionie");
+        assert_eq!(masculine(1_000_000_000, Case::Locative), "miliardzie");
+        assert_eq!(masculine(21_000, Case::Genitive), "dwudziestu jeden tysięcy");
+        assert_eq!(masculine(345, Case::Instrumental), "trzystoma czterdziestoma pięcioma");
+        assert_eq!(masculine(1, Case::Dative), "jednemu");
+    }
+
+    #[test]
+    fn displays_feminine_cases() {
+        let feminine = |number, case| declined(number, Gender::Feminine, case);
+
+        assert_eq!(feminine(1, Case::Accusative), "jedną");
+        assert_eq!(feminine(2, Case::Instrumental), "dwiema");
+        assert_eq!(feminine(3, Case::Genitive), "trzech");
+        assert_eq!(feminine(32, Case::Dative), "trzydziestu dwóm");
+    }
+
+    #[test]
+    fn displays_negatives() {
+        assert_eq!(words(-5_000), "minus pięć tysięcy");
+        assert_eq!(declined(-2, Gender::Masculine, Case::Genitive), "minus dwóch");
+    }
+
+    #[test]
+    fn categorises_plurals() {
+        let category = |count| Polish.plural_category(count);
+
+        assert_eq!(category(1), PluralCategory::One);
+        assert_eq!(category(21), PluralCategory::Many);
+        assert_eq!(category(2), PluralCategory::Few);
+        assert_eq!(category(24), PluralCategory::Few);
+        assert_eq!(category(12), PluralCategory::Many);
+        assert_eq!(category(5), PluralCategory::Many);
+        assert_eq!(category(0), PluralCategory::Many);
+        assert_eq!(category(102), PluralCategory::Few);
+    }
+}
@@ -0,0 +1,331 @@
+use alloc::vec;
+
+use crate::{
+    languages::{Case, Gender, Language, PluralCategory},
+    numbers_to_words::NumbersToWords,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Russian;
+
+// A word in the nominative, genitive, dative, accusative, instrumental and prepositional,
+// the accusative being the one used for things rather than people
+type Forms = [&'static str; 6];
+
+const ZERO: Forms = ["ноль", "ноля", "нолю", "ноль", "нолём", "ноле"];
+
+// One agrees with the noun in gender as well as case
+const ONE_MASCULINE: Forms = ["один", "одного", "одному", "один", "одним", "одном"];
+const ONE_FEMININE: Forms = ["одна", "одной", "одной", "одну", "одной", "одной"];
+const ONE_NEUTER: Forms = ["одно", "одного", "одному", "одно", "одним", "одном"];
+
+// Two is "две" for feminine nouns and "два" for the rest
+const TWO_FEMININE: Forms = ["две", "двух", "двум", "две", "двумя", "двух"];
+
+const UNITS: [Forms; 20] = [
+    ZERO,
+    ONE_MASCULINE,
+    ["два", "двух", "двум", "два", "двумя", "двух"],
+    ["три", "трёх", "трём", "три", "тремя", "трёх"],
+    ["четыре", "четырёх", "четырём", "четыре", "четырьмя", "четырёх"],
+    ["пять", "пяти", "пяти", "пять", "пятью", "пяти"],
+    ["шесть", "шести", "шести", "шесть", "шестью", "шести"],
+    ["семь", "семи", "семи", "семь", "семью", "семи"],
+    ["восемь", "восьми", "восьми", "восемь", "восемью", "восьми"],
+    ["девять", "девяти", "девяти", "девять", "девятью", "девяти"],
+    ["десять", "десяти", "десяти", "десять", "десятью", "десяти"],
+    ["одиннадцать", "одиннадцати", "одиннадцати", "одиннадцать", "одиннадцатью", "одиннадцати"],
+    ["двенадцать", "двенадцати", "двенадцати", "двенадцать", "двенадцатью", "двенадцати"],
+    ["тринадцать", "тринадцати", "тринадцати", "тринадцать", "тринадцатью", "тринадцати"],
+    ["четырнадцать", "четырнадцати", "четырнадцати", "четырнадцать", "четырнадцатью", "четырнадцати"],
+    ["пятнадцать", "пятнадцати", "пятнадцати", "пятнадцать", "пятнадцатью", "пятнадцати"],
+    ["шестнадцать", "шестнадцати", "шестнадцати", "шестнадцать", "шестнадцатью", "шестнадцати"],
+    ["семнадцать", "семнадцати", "семнадцати", "семнадцать", "семнадцатью", "семнадцати"],
+    ["восемнадцать", "восемнадцати", "восемнадцати", "восемнадцать", "восемнадцатью", "восемнадцати"],
+    ["девятнадцать", "девятнадцати", "девятнадцати", "девятнадцать", "девятнадцатью", "девятнадцати"],
+];
+
+const TENS: [Forms; 10] = [
+    [""; 6],
+    [""; 6],
+    ["двадцать", "двадцати", "двадцати", "двадцать", "двадцатью", "двадцати"],
+    ["тридцать", "тридцати", "тридцати", "тридцать", "тридцатью", "тридцати"],
+    ["сорок", "сорока", "сорока", "сорок", "сорока", "сорока"],
+    ["пятьдесят", "пятидесяти", "пятидесяти", "пятьдесят", "пятьюдесятью", "пятидесяти"],
+    ["шестьдесят", "шестидесяти", "шестидесяти", "шестьдесят", "шестьюдесятью", "шестидесяти"],
+    ["семьдесят", "семидесяти", "семидесяти", "семьдесят", "семьюдесятью", "семидесяти"],
+    ["восемьдесят", "восьмидесяти", "восьмидесяти", "восемьдесят", "восемьюдесятью", "восьмидесяти"],
+    ["девяносто", "девяноста", "девяноста", "девяносто", "девяноста", "девяноста"],
+];
+
+const HUNDREDS: [Forms; 10] = [
+    [""; 6],
+    ["сто", "ста", "ста", "сто", "ста", "ста"],
+    ["двести", "двухсот", "двумстам", "двести", "двумястами", "двухстах"],
+    ["триста", "трёхсот", "трёмстам", "триста", "тремястами", "трёхстах"],
+    ["четыреста", "четырёхсот", "четырёмстам", "четыреста", "четырьмястами", "четырёхстах"],
+    ["пятьсот", "пятисот", "пятистам", "пятьсот", "пятьюстами", "пятистах"],
+    ["шестьсот", "шестисот", "шестистам", "шестьсот", "шестьюстами", "шестистах"],
+    ["семьсот", "семисот", "семистам", "семьсот", "семьюстами", "семистах"],
+    ["восемьсот", "восьмисот", "восьмистам", "восемьсот", "восемьюстами", "восьмистах"],
+    ["девятьсот", "девятисот", "девятистам", "девятьсот", "девятьюстами", "девятистах"],
+];
+
+// Thousand is a feminine noun, singular forms then plural
+const THOUSAND: (Forms, Forms) = (
+    ["тысяча", "тысячи", "тысяче", "тысячу", "тысячей", "тысяче"],
+    ["тысячи", "тысяч", "тысячам", "тысячи", "тысячами", "тысячах"],
+);
+
+// Short scale masculine nouns, each a thousand times the one before, starting at 10^6
+const SCALES: [&str; 11] = [
+    "миллион",
+    "миллиард",
+    "триллион",
+    "квадриллион",
+    "квинтиллион",
+    "секстиллион",
+    "септиллион",
+    "октиллион",
+    "нониллион",
+    "дециллион",
+    "ундециллион",
+];
+
+const SINGULAR_ENDINGS: Forms = ["", "а", "у", "", "ом", "е"];
+const PLURAL_ENDINGS: Forms = ["ы", "ов", "ам", "ы", "ами", "ах"];
+
+fn unit(n: u128, gender: Gender, case: Case) -> &'static str {
+    let forms = match (n, gender) {
+        (1, Gender::Masculine) => ONE_MASCULINE,
+        (1, Gender::Feminine) => ONE_FEMININE,
+        (1, Gender::Neuter) => ONE_NEUTER,
+        (2, Gender::Feminine) => TWO_FEMININE,
+        _ => UNITS[n as usize],
+    };
+
+    forms[case as usize]
+}
+
+// Every word of a compound number takes the case, "двадцати пяти" for twenty five in the genitive
+fn below_thousand(f: &mut core::fmt::Formatter<'_>, n: u128, gender: Gender, case: Case) -> core::fmt::Result {
+    let (hundreds, rest) = (n / 100, n % 100);
+    let (tens, units) = (rest / 10, rest % 10);
+
+    let hundred = (hundreds > 0).then(|| HUNDREDS[hundreds as usize][case as usize]);
+    let (ten, unit) = match rest {
+        0 => (None, None),
+        1..=19 => (None, Some(unit(rest, gender, case))),
+        _ => (Some(TENS[tens as usize][case as usize]), (units > 0).then(|| unit(units, gender, case))),
+    };
+
+    let mut separator = "";
+    for word in [hundred, ten, unit].into_iter().flatten() {
+        write!(f, "{}{}", separator, word)?;
+        separator = " ";
+    }
+
+    Ok(())
+}
+
+// After one the noun is singular, after two to four it is genitive singular and after
+// five upwards genitive plural, though only in the nominative and accusative
+fn counted(category: PluralCategory, case: Case) -> (bool, Case) {
+    match (category, case) {
+        (PluralCategory::One, case) => (false, case),
+        (PluralCategory::Few, Case::Nominative | Case::Accusative) => (false, Case::Genitive),
+        (_, Case::Nominative | Case::Accusative) => (true, Case::Genitive),
+        (_, case) => (true, case),
+    }
+}
+
+fn write_scale(f: &mut core::fmt::Formatter<'_>, index: usize, plural: bool, case: Case) -> core::fmt::Result {
+    let case = case as usize;
+    match (index, plural) {
+        (1, false) => write!(f, "{}", THOUSAND.0[case]),
+        (1, true) => write!(f, "{}", THOUSAND.1[case]),
+        (_, false) => write!(f, "{}{}", SCALES[index - 2], SINGULAR_ENDINGS[case]),
+        (_, true) => write!(f, "{}{}", SCALES[index - 2], PLURAL_ENDINGS[case]),
+    }
+}
+
+impl Language for Russian {
+    fn negative_word(&self) -> &'static str {
+        "минус"
+    }
+
+    fn plural_category(&self, count: u128) -> PluralCategory {
+        match (count % 10, count % 100) {
+            (1, rest) if rest != 11 => PluralCategory::One,
+            (2..=4, rest) if !(12..=14).contains(&rest) => PluralCategory::Few,
+            _ => PluralCategory::Many,
+        }
+    }
+
+    fn write_cardinal(
+        words: &NumbersToWords<u128, Self>,
+        f: &mut core::fmt::Formatter<'_>,
+    ) -> core::fmt::Result {
+        if words.number == 0 {
+            return write!(f, "{}", ZERO[words.settings.case as usize]);
+        }
+
+        let mut groups = vec![];
+        let mut rest = words.number;
+        while rest > 0 {
+            groups.push(rest % 1000);
+            rest /= 1000;
+        }
+
+        let mut first = true;
+        for (index, group) in groups.iter().copied().enumerate().rev() {
+            if group == 0 {
+                continue;
+            }
+
+            if !first {
+                write!(f, " ")?;
+            }
+            first = false;
+
+            if index == 0 {
+                below_thousand(f, group, words.settings.gender, words.settings.case)?;
+                continue;
+            }
+
+            // A thousand on its own is just "тысяча", and the scales agree with their count
+            let gender = if index == 1 { Gender::Feminine } else { Gender::Masculine };
+            if index > 1 || group != 1 {
+                below_thousand(f, group, gender, words.settings.case)?;
+                write!(f, " ")?;
+            }
+
+            let (plural, case) = counted(Russian.plural_category(group), words.settings.case);
+            write_scale(f, index, plural, case)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{format, string::String};
+
+    use super::Russian;
+    use crate::{
+        languages::{Case, Gender, Language, PluralCategory},
+        numbers_to_words::NumbersToWords,
+    };
+
+    fn words(number: i64) -> String {
+        format!("{}", NumbersToWords::new(number).language(Russian))
+    }
+
+    fn declined(number: i64, gender: Gender, case: Case) -> String {
+        format!("{}", NumbersToWords::new(number).language(Russian).gender(gender).case(case))
+    }
+
+    #[test]
+    fn displays_below_one_thousand() {
+        assert_eq!(words(0), "ноль");
+        assert_eq!(words(1), "один");
+        assert_eq!(words(11), "одиннадцать");
+        assert_eq!(words(21), "двадцать один");
+        assert_eq!(words(40), "сорок");
+        assert_eq!(words(99), "девяносто девять");
+        assert_eq!(words(100), "сто");
+        assert_eq!(words(101), "сто один");
+        assert_eq!(words(342), "триста сорок два");
+        assert_eq!(words(999), "девятьсот девяносто девять");
+    }
+
+    #[test]
+    fn displays_scale_agreement() {
+        assert_eq!(words(1_000), "тысяча");
+        assert_eq!(words(2_000), "две тысячи");
+        assert_eq!(words(4_000), "четыре тысячи");
+        assert_eq!(words(5_000), "пять тысяч");
+        assert_eq!(words(11_000), "одиннадцать тысяч");
+        assert_eq!(words(12_000), "двенадцать тысяч");
+        assert_eq!(words(21_000), "двадцать одна тысяча");
+        assert_eq!(words(22_000), "двадцать две тысячи");
+        assert_eq!(words(25_000), "двадцать пять тысяч");
+        assert_eq!(words(1_234), "тысяча двести тридцать четыре");
+        assert_eq!(words(1_000_000), "один миллион");
+        assert_eq!(words(2_000_000), "два миллиона");
+        assert_eq!(words(5_000_000), "пять миллионов");
+        assert_eq!(words(21_000_000), "двадцать один миллион");
+        assert_eq!(words(113_000_000_000), "сто тринадцать миллиардов");
+        assert_eq!(words(3_002_001), "три миллиона две тысячи один");
+
+        let actual = NumbersToWords::new(10_u128.pow(36)).language(Russian);
+        assert_eq!(format!("{actual}"), "один ундециллион");
+    }
+
+    #[test]
+    fn displays_gender() {
+        assert_eq!(declined(1, Gender::Feminine, Case::Nominative), "одна");
+        assert_eq!(declined(1, Gender::Neuter, Case::Nominative), "одно");
+        assert_eq!(declined(2, Gender::Feminine, Case::Nominative), "две");
+        assert_eq!(declined(2, Gender::Neuter, Case::Nominative), "два");
+        assert_eq!(declined(31, Gender::Feminine, Case::Nominative), "тридцать одна");
+        assert_eq!(declined(2_002, Gender::Feminine, Case::Nominative), "две тысячи две");
+        assert_eq!(declined(2_000_001, Gender::Neuter, Case::Nominative), "два миллиона одно");
+    }
+
+    #[test]
+    fn displays_cases() {
+        let masculine = |number, case| declined(number, Gender::Masculine, case);
+
+        assert_eq!(masculine(2_000, Case::Genitive), "двух тысяч");
+        assert_eq!(masculine(2_000, Case::Dative), "двум тысячам");
+        assert_eq!(masculine(2_000, Case::Accusative), "две тысячи");
+        assert_eq!(masculine(2_000, Case::Instrumental), "двумя тысячами");
+        assert_eq!(masculine(2_000, Case::Locative), "двух тысячах");
+        assert_eq!(masculine(5_000, Case::Genitive), "пяти тысяч");
+        assert_eq!(masculine(5_000, Case::Instrumental), "пятью тысячами");
+        assert_eq!(masculine(1_000, Case::Accusative), "тысячу");
+        assert_eq!(masculine(1_000, Case::Instrumental), "тысячей");
+        assert_eq!(masculine(21_000, Case::Genitive), "двадцати одной тысячи");
+        assert_eq!(masculine(1_000_000, Case::Dative), "одному миллиону");
+        assert_eq!(masculine(3_000_000, Case::Locative), "трёх миллионах");
+        assert_eq!(masculine(548, Case::Instrumental), "пятьюстами сорока восемью");
+        assert_eq!(masculine(290, Case::Genitive), "двухсот девяноста");
+        assert_eq!(masculine(0, Case::Instrumental), "нолём");
+    }
+
+    #[test]
+    fn displays_feminine_cases() {
+        let feminine = |number, case| declined(number, Gender::Feminine, case);
+
+        assert_eq!(feminine(1, Case::Accusative), "одну");
+        assert_eq!(feminine(21, Case::Accusative), "двадцать одну");
+        assert_eq!(feminine(1, Case::Genitive), "одной");
+        assert_eq!(feminine(2, Case::Instrumental), "двумя");
+        assert_eq!(feminine(42, Case::Dative), "сорока двум");
+    }
+
+    #[test]
+    fn displays_negatives() {
+        assert_eq!(words(-2_000), "минус две тысячи");
+        assert_eq!(declined(-5, Gender::Masculine, Case::Genitive), "минус пяти");
+    }
+
+    #[test]
+    fn categorises_plurals() {
+        let category = |count| Russian.plural_category(count);
+
+        assert_eq!(category(1), PluralCategory::One);
+        assert_eq!(category(21), PluralCategory::One);
+        assert_eq!(category(101), PluralCategory::One);
+        assert_eq!(category(11), PluralCategory::Many);
+        assert_eq!(category(2), PluralCategory::Few);
+        assert_eq!(category(34), PluralCategory::Few);
+        assert_eq!(category(12), PluralCategory::Many);
+        assert_eq!(category(14), PluralCategory::Many);
+        assert_eq!(category(0), PluralCategory::Many);
+        assert_eq!(category(5), PluralCategory::Many);
+        assert_eq!(category(111), PluralCategory::Many);
+    }
+}
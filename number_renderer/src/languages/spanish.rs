@@ -0,0 +1,254 @@
+use alloc::vec;
+
+use crate::{
+    languages::{Gender, Language, PluralCategory},
+    numbers_to_words::NumbersToWords,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Spanish;
+
+const UNITS: [&str; 30] = [
+    "cero", "uno", "dos", "tres", "cuatro", "cinco", "seis", "siete", "ocho", "nueve", "diez",
+    "once", "doce", "trece", "catorce", "quince", "dieciséis", "diecisiete", "dieciocho",
+    "diecinueve", "veinte", "veintiuno", "veintidós", "veintitrés", "veinticuatro", "veinticinco",
+    "veintiséis", "veintisiete", "veintiocho", "veintinueve",
+];
+
+const TENS: [&str; 10] = [
+    "", "", "", "treinta", "cuarenta", "cincuenta", "sesenta", "setenta", "ochenta", "noventa",
+];
+
+// Stems that take "os" or "as" to agree with the noun
+const HUNDREDS: [&str; 10] = [
+    "", "", "doscient", "trescient", "cuatrocient", "quinient", "seiscient", "setecient",
+    "ochocient", "novecient",
+];
+
+// Long scale, each name a million times the one before, starting at 10^6
+const SCALES: [(&str, &str); 6] = [
+    ("millón", "millones"),
+    ("billón", "billones"),
+    ("trillón", "trillones"),
+    ("cuatrillón", "cuatrillones"),
+    ("quintillón", "quintillones"),
+    ("sextillón", "sextillones"),
+];
+
+// The forms a trailing one takes, "uno" on its own, "un" before a noun and "una" for feminine nouns
+#[derive(Clone, Copy, PartialEq)]
+enum One {
+    Full,
+    Short,
+    Feminine,
+}
+
+fn one(form: One) -> &'static str {
+    match form {
+        One::Full => "uno",
+        One::Short => "un",
+        One::Feminine => "una",
+    }
+}
+
+fn below_hundred(f: &mut core::fmt::Formatter<'_>, n: u128, form: One) -> core::fmt::Result {
+    let (tens, units) = (n / 10, n % 10);
+    match n {
+        1 => write!(f, "{}", one(form)),
+        21 if form == One::Short => write!(f, "veintiún"),
+        21 if form == One::Feminine => write!(f, "veintiuna"),
+        0..=29 => write!(f, "{}", UNITS[n as usize]),
+        _ if units == 0 => write!(f, "{}", TENS[tens as usize]),
+        _ if units == 1 => write!(f, "{} y {}", TENS[tens as usize], one(form)),
+        _ => write!(f, "{} y {}", TENS[tens as usize], UNITS[units as usize]),
+    }
+}
+
+fn below_thousand(f: &mut core::fmt::Formatter<'_>, n: u128, form: One) -> core::fmt::Result {
+    let (hundreds, rest) = (n / 100, n % 100);
+    let ending = if form == One::Feminine { "as" } else { "os" };
+    match hundreds {
+        0 => return below_hundred(f, rest, form),
+        // A bare hundred is "cien", and "ciento" when more follows
+        1 if rest == 0 => return write!(f, "cien"),
+        1 => write!(f, "ciento")?,
+        _ => write!(f, "{}{}", HUNDREDS[hundreds as usize], ending)?,
+    }
+
+    if rest > 0 {
+        write!(f, " ")?;
+        below_hundred(f, rest, form)?;
+    }
+
+    Ok(())
+}
+
+// "mil" is never preceded by "un", and the thousands agree with the noun like the units do
+fn below_million(f: &mut core::fmt::Formatter<'_>, n: u128, form: One) -> core::fmt::Result {
+    let (thousands, rest) = (n / 1000, n % 1000);
+    match thousands {
+        0 => return below_thousand(f, rest, form),
+        1 => write!(f, "mil")?,
+        _ => {
+            let thousands_form = if form == One::Feminine { One::Feminine } else { One::Short };
+            below_thousand(f, thousands, thousands_form)?;
+            write!(f, " mil")?;
+        }
+    }
+
+    if rest > 0 {
+        write!(f, " ")?;
+        below_thousand(f, rest, form)?;
+    }
+
+    Ok(())
+}
+
+impl Language for Spanish {
+    fn negative_word(&self) -> &'static str {
+        "menos"
+    }
+
+    // Whole millions take "de" before the noun
+    fn plural_category(&self, count: u128) -> PluralCategory {
+        match count {
+            1 => PluralCategory::One,
+            _ if count != 0 && count.is_multiple_of(1_000_000) => PluralCategory::Many,
+            _ => PluralCategory::Other,
+        }
+    }
+
+    fn write_cardinal(
+        words: &NumbersToWords<u128, Self>,
+        f: &mut core::fmt::Formatter<'_>,
+    ) -> core::fmt::Result {
+        let mut groups = vec![];
+        let mut rest = words.number / 1_000_000;
+        while rest > 0 {
+            groups.push(rest % 1_000_000);
+            rest /= 1_000_000;
+        }
+
+        let mut first = true;
+        for (index, group) in groups.iter().copied().enumerate().rev() {
+            if group == 0 {
+                continue;
+            }
+
+            if !first {
+                write!(f, " ")?;
+            }
+            first = false;
+
+            // The scale words are masculine nouns, "un millón" and "veintiún millones"
+            let (singular, plural) = SCALES[index];
+            match group {
+                1 => write!(f, "un {}", singular)?,
+                _ => {
+                    below_million(f, group, One::Short)?;
+                    write!(f, " {}", plural)?;
+                }
+            }
+        }
+
+        let rest = words.number % 1_000_000;
+        if rest > 0 || first {
+            if !first {
+                write!(f, " ")?;
+            }
+
+            let form = match (words.settings.gender, words.settings.before_noun) {
+                (Gender::Feminine, _) => One::Feminine,
+                (_, true) => One::Short,
+                (_, false) => One::Full,
+            };
+            below_million(f, rest, form)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{format, string::String};
+
+    use super::Spanish;
+    use crate::{languages::Gender, numbers_to_words::NumbersToWords};
+
+    fn words(number: i64) -> String {
+        format!("{}", NumbersToWords::new(number).language(Spanish))
+    }
+
+    fn feminine(number: i64) -> String {
+        format!("{}", NumbersToWords::new(number).language(Spanish).gender(Gender::Feminine))
+    }
+
+    #[test]
+    fn displays_below_one_hundred() {
+        let expected = [
+            "cero", "uno", "dos", "tres", "cuatro", "cinco", "seis", "siete", "ocho", "nueve",
+            "diez", "once", "doce", "trece", "catorce", "quince", "dieciséis", "diecisiete", "dieciocho", "diecinueve",
+            "veinte", "veintiuno", "veintidós", "veintitrés", "veinticuatro", "veinticinco", "veintiséis", "veintisiete", "veintiocho", "veintinueve",
+            "treinta", "treinta y uno", "treinta y dos", "treinta y tres", "treinta y cuatro", "treinta y cinco", "treinta y seis", "treinta y siete", "treinta y ocho", "treinta y nueve",
+            "cuarenta", "cuarenta y uno", "cuarenta y dos", "cuarenta y tres", "cuarenta y cuatro", "cuarenta y cinco", "cuarenta y seis", "cuarenta y siete", "cuarenta y ocho", "cuarenta y nueve",
+            "cincuenta", "cincuenta y uno", "cincuenta y dos", "cincuenta y tres", "cincuenta y cuatro", "cincuenta y cinco", "cincuenta y seis", "cincuenta y siete", "cincuenta y ocho", "cincuenta y nueve",
+            "sesenta", "sesenta y uno", "sesenta y dos", "sesenta y tres", "sesenta y cuatro", "sesenta y cinco", "sesenta y seis", "sesenta y siete", "sesenta y ocho", "sesenta y nueve",
+            "setenta", "setenta y uno", "setenta y dos", "setenta y tres", "setenta y cuatro", "setenta y cinco", "setenta y seis", "setenta y siete", "setenta y ocho", "setenta y nueve",
+            "ochenta", "ochenta y uno", "ochenta y dos", "ochenta y tres", "ochenta y cuatro", "ochenta y cinco", "ochenta y seis", "ochenta y siete", "ochenta y ocho", "ochenta y nueve",
+            "noventa", "noventa y uno", "noventa y dos", "noventa y tres", "noventa y cuatro", "noventa y cinco", "noventa y seis", "noventa y siete", "noventa y ocho", "noventa y nueve",
+        ];
+
+        expected
+            .iter()
+            .enumerate()
+            .for_each(|(n, word)| assert_eq!(words(n as i64), *word));
+    }
+
+    #[test]
+    fn displays_hundreds_and_thousands() {
+        assert_eq!(words(100), "cien");
+        assert_eq!(words(101), "ciento uno");
+        assert_eq!(words(200), "doscientos");
+        assert_eq!(words(555), "quinientos cincuenta y cinco");
+        assert_eq!(words(777), "setecientos setenta y siete");
+        assert_eq!(words(999), "novecientos noventa y nueve");
+        assert_eq!(words(1_000), "mil");
+        assert_eq!(words(1_001), "mil uno");
+        assert_eq!(words(21_000), "veintiún mil");
+        assert_eq!(words(100_000), "cien mil");
+        assert_eq!(words(101_000), "ciento un mil");
+    }
+
+    #[test]
+    fn displays_long_scale() {
+        assert_eq!(words(1_000_000), "un millón");
+        assert_eq!(words(2_500_000), "dos millones quinientos mil");
+        assert_eq!(words(21_000_000), "veintiún millones");
+        assert_eq!(words(1_000_000_000), "mil millones");
+        assert_eq!(words(1_000_000_000_000), "un billón");
+        assert_eq!(words(3_000_001_000_001), "tres billones un millón uno");
+        assert_eq!(words(1_000_000_000_000_000_000), "un trillón");
+
+        let actual = NumbersToWords::new(10_u128.pow(36)).language(Spanish);
+        assert_eq!(format!("{actual}"), "un sextillón");
+    }
+
+    #[test]
+    fn displays_feminine() {
+        assert_eq!(feminine(1), "una");
+        assert_eq!(feminine(21), "veintiuna");
+        assert_eq!(feminine(31), "treinta y una");
+        assert_eq!(feminine(200), "doscientas");
+        assert_eq!(feminine(21_000), "veintiuna mil");
+        assert_eq!(feminine(201_000), "doscientas una mil");
+        assert_eq!(feminine(1_000_000), "un millón");
+        assert_eq!(feminine(200_000_200), "doscientos millones doscientas");
+    }
+
+    #[test]
+    fn displays_negatives() {
+        assert_eq!(words(-21), "menos veintiuno");
+        assert_eq!(format!("{}", NumbersToWords::new(-5).language(Spanish).negative_word("negativo")), "negativo cinco");
+    }
+}
@@ -0,0 +1,292 @@
+use alloc::{string::String, vec::Vec};
+
+use crate::validation_errors::ParseDigitsError;
+
+// The separators a locale writes numbers with, "1,234.5" in English or "1.234,5" in German
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NumberFormat {
+    // Accepted between digits of the integer part, along with '_'
+    pub group: &'static [char],
+    pub decimal: char,
+}
+
+impl NumberFormat {
+    pub const ENGLISH: NumberFormat = NumberFormat {
+        group: &[','],
+        decimal: '.',
+    };
+
+    pub const GERMAN: NumberFormat = NumberFormat {
+        group: &['.'],
+        decimal: ',',
+    };
+
+    // Spaces, including the narrow no-break space, group the digits
+    pub const FRENCH: NumberFormat = NumberFormat {
+        group: &[' ', '\u{a0}', '\u{202f}'],
+        decimal: ',',
+    };
+
+    pub const SWISS: NumberFormat = NumberFormat {
+        group: &['\'', '\u{2019}'],
+        decimal: '.',
+    };
+}
+
+// How input is read before it is parsed into a number
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ParseMode {
+    // Exactly as str::parse reads it
+    #[default]
+    Strict,
+    // Separators, SI suffixes, scientific notation and scale words are rewritten first
+    Lenient(NumberFormat),
+}
+
+// SI prefixes and scale words, with the power of ten they stand for. Exa is left out, as
+// "1E" is more likely a mistyped exponent than 10^18
+const SUFFIXES: [(&str, u32); 12] = [
+    ("k", 3),
+    ("K", 3),
+    ("M", 6),
+    ("G", 9),
+    ("T", 12),
+    ("P", 15),
+    ("thousand", 3),
+    ("million", 6),
+    ("billion", 9),
+    ("trillion", 12),
+    ("quadrillion", 15),
+    ("quintillion", 18),
+];
+
+// Far beyond any primitive, but keeps "1e999999999" from filling memory
+const MAX_EXPONENT: i64 = 4096;
+
+// A plain decimal rewritten from lenient input, with where each character came from
+pub(crate) struct Canonical {
+    pub(crate) text: String,
+    origins: Vec<usize>,
+    end: usize,
+}
+
+impl Canonical {
+    // Maps a position in the rewritten text back to the input
+    pub(crate) fn origin(&self, position: usize) -> usize {
+        self.origins.get(position).copied().unwrap_or(self.end)
+    }
+}
+
+fn suffix_exponent(word: &str) -> Option<u32> {
+    SUFFIXES
+        .iter()
+        .find(|(suffix, _)| match suffix.len() {
+            1 => *suffix == word,
+            _ => suffix.eq_ignore_ascii_case(word),
+        })
+        .map(|(_, exponent)| *exponent)
+}
+
+impl NumberFormat {
+    // Rewrites input such as "1,234.5", "1.2k", "3 million" or "1e6" as a plain decimal
+    // like "1234.5" or "1000000", which every number type can parse
+    pub fn normalize(&self, input: &str) -> Result<String, ParseDigitsError> {
+        self.canonical(input).map(|canonical| canonical.text)
+    }
+
+    pub(crate) fn canonical(&self, input: &str) -> Result<Canonical, ParseDigitsError> {
+        let error = |position| ParseDigitsError { position };
+
+        let start = input.len() - input.trim_start().len();
+        let end = input.trim_end().len();
+        if start >= end {
+            return Err(error(start));
+        }
+
+        let mut body = &input[start..end];
+        let negative = body.starts_with('-');
+        body = body.strip_prefix(['-', '+']).unwrap_or(body);
+        let body_start = end - body.len();
+
+        // A trailing SI prefix or scale word, attached or after a space
+        let word = body.trim_end_matches(|c: char| c.is_alphabetic());
+        let mut exponent = 0_i64;
+        let mut suffix_origin = end;
+        if word.len() < body.len() {
+            suffix_origin = body_start + word.len();
+            exponent = suffix_exponent(&body[word.len()..]).ok_or(error(suffix_origin))? as i64;
+            body = word.trim_end();
+        }
+
+        let mut integer: Vec<(char, usize)> = Vec::new();
+        let mut fraction: Vec<(char, usize)> = Vec::new();
+        let mut in_fraction = false;
+        let mut point = end;
+        let mut chars = body.char_indices().peekable();
+        while let Some((offset, c)) = chars.next() {
+            let position = body_start + offset;
+            let next_is_digit = chars.peek().is_some_and(|(_, next)| next.is_ascii_digit());
+            match c {
+                '0'..='9' if in_fraction => fraction.push((c, position)),
+                '0'..='9' => integer.push((c, position)),
+                // Separators only ever sit between two digits of the integer part
+                c if (c == '_' || self.group.contains(&c)) && !in_fraction => {
+                    let after_digit = integer.last().is_some_and(|(_, last)| *last + 1 == position);
+                    if !after_digit || !next_is_digit {
+                        return Err(error(position));
+                    }
+                }
+                c if c == self.decimal && !in_fraction && next_is_digit => {
+                    in_fraction = true;
+                    point = position;
+                }
+                'e' | 'E' if !integer.is_empty() || !fraction.is_empty() => {
+                    let digits = &body[offset + 1..];
+                    let unsigned = digits.strip_prefix(['-', '+']).unwrap_or(digits);
+                    if unsigned.is_empty() || !unsigned.bytes().all(|b| b.is_ascii_digit()) {
+                        return Err(error(position));
+                    }
+
+                    let scientific = digits
+                        .parse::<i64>()
+                        .ok()
+                        .filter(|scientific| scientific.abs() <= MAX_EXPONENT)
+                        .ok_or(error(position))?;
+
+                    exponent += scientific;
+                    suffix_origin = suffix_origin.min(position);
+                    break;
+                }
+                _ => return Err(error(position)),
+            }
+        }
+
+        if integer.is_empty() && fraction.is_empty() {
+            return Err(error(body_start + body.len()));
+        }
+
+        // Moves the decimal point, filling with zeros that point at the exponent
+        while exponent > 0 {
+            let digit = match fraction.is_empty() {
+                true => ('0', suffix_origin),
+                false => fraction.remove(0),
+            };
+            integer.push(digit);
+            exponent -= 1;
+        }
+        while exponent < 0 {
+            let digit = integer.pop().unwrap_or(('0', suffix_origin));
+            fraction.insert(0, digit);
+            exponent += 1;
+        }
+
+        let mut canonical = Canonical {
+            text: String::new(),
+            origins: Vec::new(),
+            end,
+        };
+        let mut push = |c: char, origin: usize| {
+            canonical.text.push(c);
+            canonical.origins.push(origin);
+        };
+
+        if negative {
+            push('-', start);
+        }
+
+        match integer.is_empty() {
+            true => push('0', suffix_origin),
+            false => integer.iter().for_each(|(c, origin)| push(*c, *origin)),
+        }
+
+        // An exponent can leave only zeros after the point, which integers cannot parse
+        let scaled = suffix_origin < end;
+        let redundant = scaled && fraction.iter().all(|(c, _)| *c == '0');
+        if !fraction.is_empty() && !redundant {
+            push('.', point.min(fraction[0].1));
+            fraction.iter().for_each(|(c, origin)| push(*c, *origin));
+        }
+
+        Ok(canonical)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::String;
+
+    use super::NumberFormat;
+    use crate::validation_errors::ParseDigitsError;
+
+    fn english(input: &str) -> Result<String, ParseDigitsError> {
+        NumberFormat::ENGLISH.normalize(input)
+    }
+
+    fn error(position: usize) -> Result<String, ParseDigitsError> {
+        Err(ParseDigitsError { position })
+    }
+
+    #[test]
+    fn accepts_plain_numbers() {
+        assert_eq!(english("42").as_deref(), Ok("42"));
+        assert_eq!(english(" 42 ").as_deref(), Ok("42"));
+        assert_eq!(english("-7").as_deref(), Ok("-7"));
+        assert_eq!(english("+7").as_deref(), Ok("7"));
+        assert_eq!(english("3.14").as_deref(), Ok("3.14"));
+        assert_eq!(english(".5").as_deref(), Ok("0.5"));
+    }
+
+    #[test]
+    fn accepts_separators() {
+        assert_eq!(english("1,000,000").as_deref(), Ok("1000000"));
+        assert_eq!(english("1_000").as_deref(), Ok("1000"));
+        assert_eq!(english("-1,234.56").as_deref(), Ok("-1234.56"));
+        assert_eq!(NumberFormat::GERMAN.normalize("1.234,56").as_deref(), Ok("1234.56"));
+        assert_eq!(NumberFormat::FRENCH.normalize("1 234 567,8").as_deref(), Ok("1234567.8"));
+        assert_eq!(NumberFormat::FRENCH.normalize("1\u{202f}234").as_deref(), Ok("1234"));
+        assert_eq!(NumberFormat::SWISS.normalize("1'234.5").as_deref(), Ok("1234.5"));
+    }
+
+    #[test]
+    fn accepts_scientific_notation() {
+        assert_eq!(english("1e6").as_deref(), Ok("1000000"));
+        assert_eq!(english("1.5E3").as_deref(), Ok("1500"));
+        assert_eq!(english("1.25e1").as_deref(), Ok("12.5"));
+        assert_eq!(english("2.5e-1").as_deref(), Ok("0.25"));
+        assert_eq!(english("-4e+2").as_deref(), Ok("-400"));
+        assert_eq!(english("5e-3").as_deref(), Ok("0.005"));
+    }
+
+    #[test]
+    fn accepts_suffixes_and_scale_words() {
+        assert_eq!(english("1.2k").as_deref(), Ok("1200"));
+        assert_eq!(english("3K").as_deref(), Ok("3000"));
+        assert_eq!(english("2.5M").as_deref(), Ok("2500000"));
+        assert_eq!(english("1G").as_deref(), Ok("1000000000"));
+        assert_eq!(english("3 million").as_deref(), Ok("3000000"));
+        assert_eq!(english("1.5 Billion").as_deref(), Ok("1500000000"));
+        assert_eq!(english("-2 thousand").as_deref(), Ok("-2000"));
+        assert_eq!(english("1.2345k").as_deref(), Ok("1234.5"));
+    }
+
+    #[test]
+    fn rejects_malformed_input() {
+        assert_eq!(english(""), error(0));
+        assert_eq!(english("   "), error(3));
+        assert_eq!(english("-"), error(1));
+        assert_eq!(english("1,,000"), error(1));
+        assert_eq!(english(",100"), error(0));
+        assert_eq!(english("100,"), error(3));
+        assert_eq!(english("1.000,5"), error(5));
+        assert_eq!(english("1.2.3"), error(3));
+        assert_eq!(english("3."), error(1));
+        assert_eq!(english("1e"), error(1));
+        assert_eq!(english("1E"), error(1));
+        assert_eq!(english("2 E"), error(2));
+        assert_eq!(english("1e+"), error(1));
+        assert_eq!(english("1e99999"), error(1));
+        assert_eq!(english("3 millions"), error(2));
+        assert_eq!(english("1m"), error(1));
+        assert_eq!(english("12a4"), error(2));
+    }
+}
@@ -1,8 +1,51 @@
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+mod approximate;
+mod calendar;
+mod casing;
+mod counted;
+mod currency;
+mod decimal;
+mod digit_string;
+mod digits;
+mod languages;
+mod lenient;
+mod numbers_to_roman;
 mod numbers_to_words;
+mod ordinal;
+mod render_options;
+mod roman_to_numbers;
+mod scales;
+#[cfg(feature = "serde")]
+mod serialization;
+mod text_to_words;
 mod validator;
 mod validation_errors;
+mod words_to_numbers;
+mod writer;
 
-pub use crate::numbers_to_words::NumbersToWords;
-pub use crate::validator::Validator;
-pub use crate::validation_errors::InputError;
\ No newline at end of file
+pub use crate::approximate::{Approximate, Rounding};
+pub use crate::calendar::{Date, Time, TimeStyle, Year};
+pub use crate::casing::Casing;
+pub use crate::counted::{Counted, Noun};
+pub use crate::currency::{Currency, CurrencyUnit, MinorStyle, Money, CURRENCIES};
+pub use crate::decimal::Decimal;
+pub use crate::digit_string::DigitString;
+pub use crate::digits::{DigitStyle, Digits};
+pub use crate::languages::{Case, English, French, Gender, German, Language, PluralCategory, Polish, Russian, Spanish};
+pub use crate::lenient::{NumberFormat, ParseMode};
+pub use crate::numbers_to_roman::NumbersToRoman;
+pub use crate::numbers_to_words::{DecimalStyle, NumbersToWords};
+pub use crate::ordinal::Ordinal;
+pub use crate::render_options::{AndPlacement, RenderOptions};
+pub use crate::roman_to_numbers::RomanToNumbers;
+pub use crate::text_to_words::{Category, Expansion, TextToWords};
+#[cfg(feature = "serde")]
+pub use crate::serialization::validated;
+pub use crate::validator::{CheckedRem, Rule, Rules, Validator};
+pub use crate::validation_errors::{InputError, ParseDigitsError, ParsePosition, ParseRomanError, ParseWordsError};
+pub use crate::words_to_numbers::WordsToNumbers;
+pub use crate::writer::WriteWords;
\ No newline at end of file
@@ -0,0 +1,222 @@
+use alloc::string::ToString;
+use core::fmt::{Display, Write};
+
+use crate::{
+    roman_to_numbers::RomanToNumbers,
+    validation_errors::{InputError, ParsePosition},
+    validator::{Rules, Validator},
+};
+
+// Each numeral with its value, subtractive pairs included, largest first
+const NUMERALS: [(&str, u32); 13] = [
+    ("M", 1000),
+    ("CM", 900),
+    ("D", 500),
+    ("CD", 400),
+    ("C", 100),
+    ("XC", 90),
+    ("L", 50),
+    ("XL", 40),
+    ("X", 10),
+    ("IX", 9),
+    ("V", 5),
+    ("IV", 4),
+    ("I", 1),
+];
+
+pub(crate) const MAX_NUMERAL: u32 = 3_999;
+
+// A bar over the thousands multiplies them by a thousand
+pub(crate) const MAX_VINCULUM: u32 = 3_999_999;
+
+// Combining overline, written after each numeral it covers
+pub(crate) const OVERLINE: char = '\u{305}';
+
+pub struct NumbersToRoman<N> {
+    pub(crate) number: N,
+    pub(crate) vinculum: bool,
+}
+
+impl<N> NumbersToRoman<N> {
+    pub fn new(input: N) -> Self {
+        NumbersToRoman {
+            number: input,
+            vinculum: false,
+        }
+    }
+
+    // Writes values past 3999, up to 3,999,999, with a bar over the thousands, 4000 as "I̅V̅"
+    pub fn vinculum(mut self) -> Self {
+        self.vinculum = true;
+        self
+    }
+}
+
+fn write_numerals<W: Write>(out: &mut W, mut number: u32, overline: bool) -> core::fmt::Result {
+    for (numeral, value) in NUMERALS {
+        while number >= value {
+            for c in numeral.chars() {
+                out.write_char(c)?;
+                if overline {
+                    out.write_char(OVERLINE)?;
+                }
+            }
+
+            number -= value;
+        }
+    }
+
+    Ok(())
+}
+
+// The one way a value is written, with a vinculum only where plain numerals cannot reach
+pub(crate) fn write_roman<W: Write>(out: &mut W, number: u32) -> core::fmt::Result {
+    match number {
+        0..=MAX_NUMERAL => write_numerals(out, number, false),
+        _ => {
+            write_numerals(out, number / 1000, true)?;
+            write_numerals(out, number % 1000, false)
+        }
+    }
+}
+
+impl<N: Display + Copy + TryInto<u32>> Display for NumbersToRoman<N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let max = if self.vinculum { MAX_VINCULUM } else { MAX_NUMERAL };
+        match self.number.try_into() {
+            Ok(number @ 1..) if number <= max => write_roman(f, number),
+            // Numerals have no zero or negatives, so those and values past the largest are left as digits
+            _ => write!(f, "{}", self.number),
+        }
+    }
+}
+
+impl Rules<NumbersToRoman<u32>> {
+    // Accepts forms like "IIII", "VIIII" or "IC" as well as the canonical ones
+    pub fn lenient_numerals(mut self) -> Self {
+        self.non_canonical = true;
+        self
+    }
+}
+
+impl Validator for NumbersToRoman<u32> {
+    type Bounds = u32;
+    type Output = NumbersToRoman<u32>;
+    type Error = InputError<u32>;
+
+    fn check<S: AsRef<str>>(input: S, rules: &Rules<Self>) -> Result<Self::Output, Self::Error> {
+        let input = input.as_ref();
+        let parser = match rules.non_canonical {
+            true => RomanToNumbers::new(input).lenient(),
+            false => RomanToNumbers::new(input),
+        };
+
+        match parser.parse() {
+            Err(err) => Err(InputError::ParseError {
+                position: err.position(input),
+                input: input.to_string(),
+            }),
+            Ok(value) => match rules.broken(&value) {
+                Some(rule) => Err(InputError::ValidationError {
+                    input: input.to_string(),
+                    rule: rule.clone(),
+                    value,
+                }),
+                None => Ok(NumbersToRoman {
+                    number: value,
+                    vinculum: value > MAX_NUMERAL,
+                }),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{format, string::{String, ToString}};
+
+    use super::NumbersToRoman;
+    use crate::{
+        validation_errors::InputError,
+        validator::{Rule, Validator},
+    };
+
+    fn roman(number: u32) -> String {
+        format!("{}", NumbersToRoman::new(number))
+    }
+
+    #[test]
+    fn displays_numerals() {
+        let expected = [
+            "I", "II", "III", "IV", "V", "VI", "VII", "VIII", "IX", "X", "XI", "XII", "XIII", "XIV", "XV",
+            "XVI", "XVII", "XVIII", "XIX", "XX",
+        ];
+
+        expected
+            .iter()
+            .enumerate()
+            .for_each(|(n, numeral)| assert_eq!(roman(n as u32 + 1), *numeral));
+
+        assert_eq!(roman(40), "XL");
+        assert_eq!(roman(49), "XLIX");
+        assert_eq!(roman(90), "XC");
+        assert_eq!(roman(400), "CD");
+        assert_eq!(roman(444), "CDXLIV");
+        assert_eq!(roman(900), "CM");
+        assert_eq!(roman(1994), "MCMXCIV");
+        assert_eq!(roman(2024), "MMXXIV");
+        assert_eq!(roman(3999), "MMMCMXCIX");
+    }
+
+    #[test]
+    fn displays_vinculum() {
+        let vinculum = |number: u32| format!("{}", NumbersToRoman::new(number).vinculum());
+
+        assert_eq!(vinculum(12), "XII");
+        assert_eq!(vinculum(3999), "MMMCMXCIX");
+        assert_eq!(vinculum(4000), "I\u{305}V\u{305}");
+        assert_eq!(vinculum(5001), "V\u{305}I");
+        assert_eq!(vinculum(12_345), "X\u{305}I\u{305}I\u{305}CCCXLV");
+        assert_eq!(vinculum(1_000_000), "M\u{305}");
+        assert_eq!(vinculum(3_999_999), "M\u{305}M\u{305}M\u{305}C\u{305}M\u{305}X\u{305}C\u{305}I\u{305}X\u{305}CMXCIX");
+    }
+
+    #[test]
+    fn displays_digits_without_a_numeral() {
+        assert_eq!(roman(0), "0");
+        assert_eq!(roman(4000), "4000");
+        assert_eq!(format!("{}", NumbersToRoman::new(-5_i32)), "-5");
+        assert_eq!(format!("{}", NumbersToRoman::new(4_000_000_u64).vinculum()), "4000000");
+        assert_eq!(format!("{}", NumbersToRoman::new(u128::MAX)), u128::MAX.to_string());
+    }
+
+    #[test]
+    fn displays_every_integer_type() {
+        assert_eq!(format!("{}", NumbersToRoman::new(12_u8)), "XII");
+        assert_eq!(format!("{}", NumbersToRoman::new(12_i8)), "XII");
+        assert_eq!(format!("{}", NumbersToRoman::new(1984_i64)), "MCMLXXXIV");
+        assert_eq!(format!("{}", NumbersToRoman::new(9_usize)), "IX");
+    }
+
+    #[test]
+    fn validates_numerals() {
+        let actual = NumbersToRoman::validate("MCMXCIV", 1, 3999).unwrap();
+        assert_eq!(actual.number, 1994);
+        assert_eq!(format!("{actual}"), "MCMXCIV");
+
+        let actual = NumbersToRoman::validate("I\u{305}V\u{305}", 1, 5000).unwrap();
+        assert_eq!(format!("{actual}"), "I\u{305}V\u{305}");
+
+        let actual = NumbersToRoman::rules().range(1, 12).validate("XIII").err().unwrap();
+        assert!(matches!(actual, InputError::ValidationError { value: 13, rule: Rule::Range { low: 1, high: 12 }, .. }));
+
+        let actual = NumbersToRoman::validate("IIII", 1, 12).err().unwrap();
+        assert_eq!(format!("{actual}"), "IIII cannot be parsed, failed at position 1");
+
+        let actual = NumbersToRoman::rules().lenient_numerals().validate("IIII").unwrap();
+        assert_eq!(format!("{actual}"), "IV");
+
+        let actual = NumbersToRoman::rules().lenient_numerals().range(1, 50).validate("IC").err().unwrap();
+        assert!(matches!(actual, InputError::ValidationError { value: 99, .. }));
+    }
+}
@@ -1,364 +1,709 @@
-use std::{fmt::Display, str::FromStr};
+use alloc::string::ToString;
+use core::{
+    fmt::{Display, Write},
+    str::FromStr,
+};
+
+use crate::{
+    calendar::TimeStyle,
+    casing::{CaseWriter, Casing},
+    digits::DigitStyle,
+    languages::{Case, English, Gender, Language},
+    render_options::RenderOptions,
+    validation_errors::{InputError, ParsePosition},
+    lenient::ParseMode,
+    validator::{Rules, Validator},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DecimalStyle {
+    // "three point one four"
+    Point,
+    // "three and fourteen hundredths"
+    Fraction,
+}
 
-use crate::{validation_errors::InputError, validator::Validator};
+// Every setting but the number and its language, carried over whenever either is swapped
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Settings {
+    pub(crate) negative_word: Option<&'static str>,
+    pub(crate) decimal_style: DecimalStyle,
+    pub(crate) time_style: TimeStyle,
+    pub(crate) digit_style: DigitStyle,
+    pub(crate) gender: Gender,
+    pub(crate) case: Case,
+    // Set when the number is followed by the noun it counts, "un archivo" rather than "uno"
+    pub(crate) before_noun: bool,
+    pub(crate) options: RenderOptions,
+    pub(crate) casing: Casing,
+    // Written after the words, as in "one hundred only"
+    pub(crate) terminator: Option<&'static str>,
+    // Set when rendering an Ordinal, so the last word is "first" rather than "one"
+    pub(crate) ordinal: bool,
+}
 
-pub struct NumbersToWords<N>(N);
+impl Default for Settings {
+    fn default() -> Self {
+        Settings {
+            negative_word: None,
+            decimal_style: DecimalStyle::Point,
+            time_style: TimeStyle::Spoken,
+            digit_style: DigitStyle::Single,
+            gender: Gender::Masculine,
+            case: Case::Nominative,
+            before_noun: false,
+            options: RenderOptions::default(),
+            casing: Casing::Lower,
+            terminator: None,
+            ordinal: false,
+        }
+    }
+}
+
+pub struct NumbersToWords<N, L = English> {
+    pub(crate) number: N,
+    pub(crate) language: L,
+    pub(crate) settings: Settings,
+}
 
 impl<N> NumbersToWords<N> {
     pub fn new(input: N) -> Self {
-        NumbersToWords(input)
+        NumbersToWords {
+            number: input,
+            language: English,
+            settings: Settings::default(),
+        }
+    }
+}
+
+impl<N, L: Language> NumbersToWords<N, L> {
+    // Renders the words in another language, keeping every other setting
+    pub fn language<M: Language>(self, language: M) -> NumbersToWords<N, M> {
+        NumbersToWords {
+            number: self.number,
+            language,
+            settings: self.settings,
+        }
+    }
+
+    // The word written before negative numbers, the language's own word unless overridden
+    pub fn negative_word(mut self, word: &'static str) -> Self {
+        self.settings.negative_word = Some(word);
+        self
+    }
+
+    // The gender of the counted noun, for languages where numbers agree with it
+    pub fn gender(mut self, gender: Gender) -> Self {
+        self.settings.gender = gender;
+        self
+    }
+
+    // The grammatical case of the counted noun, "двух тысяч" rather than "две тысячи" in the genitive
+    pub fn case(mut self, case: Case) -> Self {
+        self.settings.case = case;
+        self
+    }
+
+    // How English words are joined and which scale names they use
+    pub fn options(mut self, options: RenderOptions) -> Self {
+        self.settings.options = options;
+        self
+    }
+
+    // How the fractional part of decimal numbers is read
+    pub fn decimal_style(mut self, style: DecimalStyle) -> Self {
+        self.settings.decimal_style = style;
+        self
+    }
+
+    // How a Time is read, "quarter past three" or "fifteen fifteen hours"
+    pub fn time_style(mut self, style: TimeStyle) -> Self {
+        self.settings.time_style = style;
+        self
+    }
+
+    // How Digits are read out, one at a time or in groups
+    pub fn digit_style(mut self, style: DigitStyle) -> Self {
+        self.settings.digit_style = style;
+        self
+    }
+
+    // "One thousand" for labels, "One Thousand" for legal documents or "ONE THOUSAND" for cheques
+    pub fn casing(mut self, casing: Casing) -> Self {
+        self.settings.casing = casing;
+        self
+    }
+
+    // A word written after the number, as in "one hundred only"
+    pub fn terminator(mut self, terminator: &'static str) -> Self {
+        self.settings.terminator = Some(terminator);
+        self
+    }
+
+    pub(crate) fn negative(&self) -> &'static str {
+        self.settings
+            .negative_word
+            .unwrap_or_else(|| self.language.negative_word())
+    }
+
+    // Whether the last word is read as a position, "first", or a count, "one"
+    pub(crate) fn with_ordinal(mut self, ordinal: bool) -> Self {
+        self.settings.ordinal = ordinal;
+        self
+    }
+
+    pub(crate) fn map<M>(self, f: impl FnOnce(N) -> M) -> NumbersToWords<M, L> {
+        NumbersToWords {
+            number: f(self.number),
+            language: self.language,
+            settings: self.settings,
+        }
+    }
+
+    // Another number rendered with the same settings, as part of this one,
+    // so casing and the terminator are left to the whole
+    pub(crate) fn with_number<M>(&self, number: M) -> NumbersToWords<M, L> {
+        NumbersToWords {
+            number,
+            language: self.language,
+            settings: Settings {
+                casing: Casing::Lower,
+                terminator: None,
+                ..self.settings
+            },
+        }
     }
 }
 
 impl<N> Validator for NumbersToWords<N>
 where
-    N: Display + FromStr + PartialOrd,
+    N: Display + FromStr + Clone,
+    N::Err: ParsePosition,
 {
     type Bounds = N;
     type Output = NumbersToWords<N>;
     type Error = InputError<N>;
 
-    fn validate(
-        input: String,
-        low: Self::Bounds,
-        high: Self::Bounds,
-    ) -> Result<Self::Output, Self::Error> {
-        match input.parse::<N>() {
-            Err(_) => Err(InputError::ParseError(input.clone())),
-            Ok(val) if val < low || val > high => Err(InputError::ValidationError(val)),
-            Ok(val) => Ok(NumbersToWords::new(val)),
+    fn check<S: AsRef<str>>(input: S, rules: &Rules<Self>) -> Result<Self::Output, Self::Error> {
+        let input = input.as_ref();
+        let parsed = match rules.mode {
+            ParseMode::Strict => input.parse::<N>().map_err(|err| err.position(input)),
+            // Parse errors in the rewritten text are reported where they came from in the input
+            ParseMode::Lenient(format) => format
+                .canonical(input)
+                .map_err(|err| err.position)
+                .and_then(|canonical| {
+                    canonical
+                        .text
+                        .parse::<N>()
+                        .map_err(|err| canonical.origin(err.position(&canonical.text)))
+                }),
+        };
+
+        match parsed {
+            Err(position) => Err(InputError::ParseError {
+                position,
+                input: input.to_string(),
+            }),
+            Ok(value) => match rules.broken(&value) {
+                Some(rule) => Err(InputError::ValidationError {
+                    input: input.to_string(),
+                    rule: rule.clone(),
+                    value,
+                }),
+                None => Ok(NumbersToWords::new(value)),
+            },
         }
     }
 }
 
-impl NumbersToWords<i64> {
-    fn render(
-        &self,
-        f: &mut std::fmt::Formatter<'_>,
-        mod_val: i64,
-        delim_val: i64,
-        mag: &str,
-    ) -> std::fmt::Result {
-        let mod_mag = self.0 % mod_val;
-        if mod_mag == 0 {
-            return write!(f, "{} {}", NumbersToWords::new(self.0 / mod_val), mag);
+// Writes the words for one kind of number, without casing or a terminator
+pub trait Render {
+    fn render(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result;
+}
+
+struct Rendered<'a, R: Render>(&'a R);
+
+impl<R: Render> Display for Rendered<'_, R> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.0.render(f)
+    }
+}
+
+impl<N, L: Language> Display for NumbersToWords<N, L>
+where
+    Self: Render,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.settings.casing == Casing::Lower && self.settings.terminator.is_none() {
+            return self.render(f);
         }
 
-        let mut delim = "";
-        if mod_mag < delim_val {
-            delim = "and ";
+        let mut out = CaseWriter::new(f, self.settings.casing);
+        write!(out, "{}", Rendered(self))?;
+        if let Some(terminator) = self.settings.terminator {
+            write!(out, " {}", terminator)?;
         }
 
-        write!(
-            f,
-            "{} {}{}",
-            NumbersToWords::new(self.0 - mod_mag),
-            delim,
-            NumbersToWords::new(mod_mag)
-        )
+        out.flush()
     }
 }
 
-impl Display for NumbersToWords<i64> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self.0 {
-            0 => write!(f, "zero"),
-            1 => write!(f, "one"),
-            2 => write!(f, "two"),
-            3 => write!(f, "three"),
-            4 => write!(f, "four"),
-            5 => write!(f, "five"),
-            6 => write!(f, "six"),
-            7 => write!(f, "seven"),
-            8 => write!(f, "eight"),
-            9 => write!(f, "nine"),
-            10 => write!(f, "ten"),
-            11 => write!(f, "eleven"),
-            12 => write!(f, "twelve"),
-            13 => write!(f, "thirteen"),
-            14 => write!(f, "fourteen"),
-            15 => write!(f, "fifteen"),
-            16 => write!(f, "sixteen"),
-            17 => write!(f, "seventeen"),
-            18 => write!(f, "eighteen"),
-            19 => write!(f, "nineteen"),
-            20 => write!(f, "twenty"),
-            30 => write!(f, "thirty"),
-            40 => write!(f, "forty"),
-            50 => write!(f, "fifty"),
-            60 => write!(f, "sixty"),
-            70 => write!(f, "seventy"),
-            80 => write!(f, "eighty"),
-            90 => write!(f, "ninety"),
-            21..=99 => {
-                let mod_ten = self.0 % 10;
-                write!(
-                    f,
-                    "{} {}",
-                    NumbersToWords::new(self.0 - mod_ten),
-                    NumbersToWords::new(mod_ten)
-                )
-            }
-            100..=999 => {
-                let mod_hundred = self.0 % 100;
-                if mod_hundred == 0 {
-                    return write!(f, "{} hundred", NumbersToWords::new(self.0 / 100));
-                }
+impl<L: Language> Render for NumbersToWords<u128, L> {
+    fn render(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        L::write_cardinal(self, f)
+    }
+}
 
-                write!(
-                    f,
-                    "{} and {}",
-                    NumbersToWords::new(self.0 - mod_hundred),
-                    NumbersToWords::new(mod_hundred)
-                )
-            }
-            1000..=9_99_999 => {
-                self.render(f, 1000, 100, "thousand")
-            }
-            1_000_000..=999_999_999 => {
-                self.render(f, 1_000_000, 1000, "million")
-            }
-            1_000_000_000..=999_999_999_999 => {
-                self.render(f, 1_000_000_000, 1_000_000, "billion")
-            }
-            1_000_000_000_000..=999_999_999_999_999 => {
-                self.render(f, 1_000_000_000_000, 1_000_000_000, "trillion")
+macro_rules! display_unsigned {
+    ($($t:ty),*) => {
+        $(
+            impl<L: Language> Render for NumbersToWords<$t, L> {
+                fn render(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    write!(f, "{}", self.with_number(self.number as u128))
+                }
             }
-            1_000_000_000_000_000 => {
-                self.render(f, 1_000_000_000_000_000, 1_000_000_000_000, "quadrillion")
+        )*
+    };
+}
+
+macro_rules! display_signed {
+    ($($t:ty),*) => {
+        $(
+            impl<L: Language> Render for NumbersToWords<$t, L> {
+                fn render(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    let magnitude = self.with_number(self.number.unsigned_abs() as u128);
+                    if self.number < 0 {
+                        return write!(f, "{} {}", self.negative(), magnitude);
+                    }
+
+                    write!(f, "{}", magnitude)
+                }
             }
-            _ => write!(f, "Invalid number"),
-        }
-    }
+        )*
+    };
 }
 
+display_unsigned!(u8, u16, u32, u64, usize);
+display_signed!(i8, i16, i32, i64, i128, isize);
 
 #[cfg(test)]
 mod tests {
+    use alloc::{format, string::ToString};
+
     use super::NumbersToWords;
+    use crate::{
+        calendar::{Date, Year},
+        casing::Casing,
+        currency::{Currency, Money},
+        languages::French,
+        render_options::RenderOptions,
+        validation_errors::InputError,
+        validator::Validator,
+    };
 
     #[test]
     fn displays_ones() {
-        let actual = NumbersToWords::new(1);
+        let actual = NumbersToWords::<i64>::new(1);
         assert_eq!(format!("{actual}"), "one");   
              
-        let actual = NumbersToWords::new(9);
+        let actual = NumbersToWords::<i64>::new(9);
         assert_eq!(format!("{actual}"), "nine");    
 
-        let actual = NumbersToWords::new(8);
+        let actual = NumbersToWords::<i64>::new(8);
         assert_eq!(format!("{actual}"), "eight");        
     }
 
     #[test]
     fn displays_teens() {
-        let actual = NumbersToWords::new(10);
+        let actual = NumbersToWords::<i64>::new(10);
         assert_eq!(format!("{actual}"), "ten");   
 
-        let actual = NumbersToWords::new(19);
+        let actual = NumbersToWords::<i64>::new(19);
         assert_eq!(format!("{actual}"), "nineteen");    
 
-        let actual = NumbersToWords::new(17);
+        let actual = NumbersToWords::<i64>::new(17);
         assert_eq!(format!("{actual}"), "seventeen");     
     }
 
     #[test]
     fn displays_hundreds() {
-        let actual = NumbersToWords::new(100);
+        let actual = NumbersToWords::<i64>::new(100);
         assert_eq!(format!("{actual}"), "one hundred");   
 
-        let actual = NumbersToWords::new(999);
+        let actual = NumbersToWords::<i64>::new(999);
         assert_eq!(format!("{actual}"), "nine hundred and ninety nine");   
 
-        let actual = NumbersToWords::new(579);
+        let actual = NumbersToWords::<i64>::new(579);
         assert_eq!(format!("{actual}"), "five hundred and seventy nine");        
 
-        let actual = NumbersToWords::new(301);
+        let actual = NumbersToWords::<i64>::new(301);
         assert_eq!(format!("{actual}"), "three hundred and one");     
     }
 
     #[test]
     fn displays_thousands() {
-        let actual = NumbersToWords::new(1_000);
+        let actual = NumbersToWords::<i64>::new(1_000);
         assert_eq!(format!("{actual}"), "one thousand");   
 
-        let actual = NumbersToWords::new(9_999);
+        let actual = NumbersToWords::<i64>::new(9_999);
         assert_eq!(format!("{actual}"), "nine thousand nine hundred and ninety nine");   
 
-        let actual = NumbersToWords::new(9999);
+        let actual = NumbersToWords::<i64>::new(9999);
         assert_eq!(format!("{actual}"), "nine thousand nine hundred and ninety nine");        
 
-        let actual = NumbersToWords::new(6047);
+        let actual = NumbersToWords::<i64>::new(6047);
         assert_eq!(format!("{actual}"), "six thousand and forty seven");     
     }
 
     #[test]
     fn displays_ten_thousands() {
-        let actual = NumbersToWords::new(10_000);
+        let actual = NumbersToWords::<i64>::new(10_000);
         assert_eq!(format!("{actual}"), "ten thousand");   
 
-        let actual = NumbersToWords::new(99_999);
+        let actual = NumbersToWords::<i64>::new(99_999);
         assert_eq!(format!("{actual}"), "ninety nine thousand nine hundred and ninety nine");   
 
-        let actual = NumbersToWords::new(67232);
+        let actual = NumbersToWords::<i64>::new(67232);
         assert_eq!(format!("{actual}"), "sixty seven thousand two hundred and thirty two");        
 
-        let actual = NumbersToWords::new(86047);
+        let actual = NumbersToWords::<i64>::new(86047);
         assert_eq!(format!("{actual}"), "eighty six thousand and forty seven");     
     }
 
     #[test]
     fn displays_hundred_thousands() {
-        let actual = NumbersToWords::new(100_000);
+        let actual = NumbersToWords::<i64>::new(100_000);
         assert_eq!(format!("{actual}"), "one hundred thousand");   
 
-        let actual = NumbersToWords::new(999_999);
+        let actual = NumbersToWords::<i64>::new(999_999);
         assert_eq!(format!("{actual}"), "nine hundred and ninety nine thousand nine hundred and ninety nine");   
 
-        let actual = NumbersToWords::new(267232);
+        let actual = NumbersToWords::<i64>::new(267232);
         assert_eq!(format!("{actual}"), "two hundred and sixty seven thousand two hundred and thirty two");        
 
-        let actual = NumbersToWords::new(900047);
+        let actual = NumbersToWords::<i64>::new(900047);
         assert_eq!(format!("{actual}"), "nine hundred thousand and forty seven");     
     }
 
     #[test]
     fn displays_millions() {
-        let actual = NumbersToWords::new(1_000_000);
+        let actual = NumbersToWords::<i64>::new(1_000_000);
         assert_eq!(format!("{actual}"), "one million");   
 
-        let actual = NumbersToWords::new(9_999_999);
+        let actual = NumbersToWords::<i64>::new(9_999_999);
         assert_eq!(format!("{actual}"), "nine million nine hundred and ninety nine thousand nine hundred and ninety nine");   
 
-        let actual = NumbersToWords::new(6267232);
+        let actual = NumbersToWords::<i64>::new(6267232);
         assert_eq!(format!("{actual}"), "six million two hundred and sixty seven thousand two hundred and thirty two");        
 
-        let actual = NumbersToWords::new(2000590);
+        let actual = NumbersToWords::<i64>::new(2000590);
         assert_eq!(format!("{actual}"), "two million and five hundred and ninety");     
     }
 
     #[test]
     fn displays_ten_millions() {
-        let actual = NumbersToWords::new(10_000_000);
+        let actual = NumbersToWords::<i64>::new(10_000_000);
         assert_eq!(format!("{actual}"), "ten million");   
 
-        let actual = NumbersToWords::new(99_999_999);
+        let actual = NumbersToWords::<i64>::new(99_999_999);
         assert_eq!(format!("{actual}"), "ninety nine million nine hundred and ninety nine thousand nine hundred and ninety nine");   
 
-        let actual = NumbersToWords::new(16267232);
+        let actual = NumbersToWords::<i64>::new(16267232);
         assert_eq!(format!("{actual}"), "sixteen million two hundred and sixty seven thousand two hundred and thirty two");        
 
-        let actual = NumbersToWords::new(12000590);
+        let actual = NumbersToWords::<i64>::new(12000590);
         assert_eq!(format!("{actual}"), "twelve million and five hundred and ninety");     
     }
 
     #[test]
     fn displays_hundred_millions() {
-        let actual = NumbersToWords::new(100_000_000);
+        let actual = NumbersToWords::<i64>::new(100_000_000);
         assert_eq!(format!("{actual}"), "one hundred million");   
 
-        let actual = NumbersToWords::new(999_999_999);
+        let actual = NumbersToWords::<i64>::new(999_999_999);
         assert_eq!(format!("{actual}"), "nine hundred and ninety nine million nine hundred and ninety nine thousand nine hundred and ninety nine");   
 
-        let actual = NumbersToWords::new(416267232);
+        let actual = NumbersToWords::<i64>::new(416267232);
         assert_eq!(format!("{actual}"), "four hundred and sixteen million two hundred and sixty seven thousand two hundred and thirty two");        
 
-        let actual = NumbersToWords::new(112000590);
+        let actual = NumbersToWords::<i64>::new(112000590);
         assert_eq!(format!("{actual}"), "one hundred and twelve million and five hundred and ninety");     
     }
 
     #[test]
     fn displays_billions() {
-        let actual = NumbersToWords::new(1_000_000_000);
+        let actual = NumbersToWords::<i64>::new(1_000_000_000);
         assert_eq!(format!("{actual}"), "one billion");   
 
-        let actual = NumbersToWords::new(9_999_999_999);
+        let actual = NumbersToWords::<i64>::new(9_999_999_999);
         assert_eq!(format!("{actual}"), "nine billion nine hundred and ninety nine million nine hundred and ninety nine thousand nine hundred and ninety nine");   
 
-        let actual = NumbersToWords::new(8416267232);
+        let actual = NumbersToWords::<i64>::new(8416267232);
         assert_eq!(format!("{actual}"), "eight billion four hundred and sixteen million two hundred and sixty seven thousand two hundred and thirty two");        
 
-        let actual = NumbersToWords::new(1000000590);
+        let actual = NumbersToWords::<i64>::new(1000000590);
         assert_eq!(format!("{actual}"), "one billion and five hundred and ninety");     
     }
 
     #[test]
     fn displays_ten_billions() {
-        let actual = NumbersToWords::new(10_000_000_000);
+        let actual = NumbersToWords::<i64>::new(10_000_000_000);
         assert_eq!(format!("{actual}"), "ten billion");   
 
-        let actual = NumbersToWords::new(99_999_999_999);
+        let actual = NumbersToWords::<i64>::new(99_999_999_999);
         assert_eq!(format!("{actual}"), "ninety nine billion nine hundred and ninety nine million nine hundred and ninety nine thousand nine hundred and ninety nine");   
 
-        let actual = NumbersToWords::new(78416267232);
+        let actual = NumbersToWords::<i64>::new(78416267232);
         assert_eq!(format!("{actual}"), "seventy eight billion four hundred and sixteen million two hundred and sixty seven thousand two hundred and thirty two");        
 
-        let actual = NumbersToWords::new(11000000590);
+        let actual = NumbersToWords::<i64>::new(11000000590);
         assert_eq!(format!("{actual}"), "eleven billion and five hundred and ninety");     
     }
 
     #[test]
     fn displays_hundred_billions() {
-        let actual = NumbersToWords::new(100_000_000_000);
+        let actual = NumbersToWords::<i64>::new(100_000_000_000);
         assert_eq!(format!("{actual}"), "one hundred billion");   
 
-        let actual = NumbersToWords::new(999_999_999_999);
+        let actual = NumbersToWords::<i64>::new(999_999_999_999);
         assert_eq!(format!("{actual}"), "nine hundred and ninety nine billion nine hundred and ninety nine million nine hundred and ninety nine thousand nine hundred and ninety nine");   
 
-        let actual = NumbersToWords::new(478416267232);
+        let actual = NumbersToWords::<i64>::new(478416267232);
         assert_eq!(format!("{actual}"), "four hundred and seventy eight billion four hundred and sixteen million two hundred and sixty seven thousand two hundred and thirty two");        
 
-        let actual = NumbersToWords::new(111000000590);
+        let actual = NumbersToWords::<i64>::new(111000000590);
         assert_eq!(format!("{actual}"), "one hundred and eleven billion and five hundred and ninety");     
     }
 
     #[test]
     fn displays_trillions() {
-        let actual = NumbersToWords::new(1_000_000_000_000);
+        let actual = NumbersToWords::<i64>::new(1_000_000_000_000);
         assert_eq!(format!("{actual}"), "one trillion");   
 
-        let actual = NumbersToWords::new(9_999_999_999_999);
+        let actual = NumbersToWords::<i64>::new(9_999_999_999_999);
         assert_eq!(format!("{actual}"), "nine trillion nine hundred and ninety nine billion nine hundred and ninety nine million nine hundred and ninety nine thousand nine hundred and ninety nine");   
 
-        let actual = NumbersToWords::new(3478416267232);
+        let actual = NumbersToWords::<i64>::new(3478416267232);
         assert_eq!(format!("{actual}"), "three trillion four hundred and seventy eight billion four hundred and sixteen million two hundred and sixty seven thousand two hundred and thirty two");        
 
-        let actual = NumbersToWords::new(1000000000590);
+        let actual = NumbersToWords::<i64>::new(1000000000590);
         assert_eq!(format!("{actual}"), "one trillion and five hundred and ninety");     
     }
 
     #[test]
     fn displays_ten_trillions() {
-        let actual = NumbersToWords::new(10_000_000_000_000);
+        let actual = NumbersToWords::<i64>::new(10_000_000_000_000);
         assert_eq!(format!("{actual}"), "ten trillion");   
 
-        let actual = NumbersToWords::new(99_999_999_999_999);
+        let actual = NumbersToWords::<i64>::new(99_999_999_999_999);
         assert_eq!(format!("{actual}"), "ninety nine trillion nine hundred and ninety nine billion nine hundred and ninety nine million nine hundred and ninety nine thousand nine hundred and ninety nine");   
 
-        let actual = NumbersToWords::new(93478416267232);
+        let actual = NumbersToWords::<i64>::new(93478416267232);
         assert_eq!(format!("{actual}"), "ninety three trillion four hundred and seventy eight billion four hundred and sixteen million two hundred and sixty seven thousand two hundred and thirty two");        
 
-        let actual = NumbersToWords::new(10000000000590);
+        let actual = NumbersToWords::<i64>::new(10000000000590);
         assert_eq!(format!("{actual}"), "ten trillion and five hundred and ninety");     
     }
 
     #[test]
     fn displays_hundred_trillions() {
-        let actual = NumbersToWords::new(100_000_000_000_000);
+        let actual = NumbersToWords::<i64>::new(100_000_000_000_000);
         assert_eq!(format!("{actual}"), "one hundred trillion");   
 
-        let actual = NumbersToWords::new(999_999_999_999_999);
+        let actual = NumbersToWords::<i64>::new(999_999_999_999_999);
         assert_eq!(format!("{actual}"), "nine hundred and ninety nine trillion nine hundred and ninety nine billion nine hundred and ninety nine million nine hundred and ninety nine thousand nine hundred and ninety nine");   
 
-        let actual = NumbersToWords::new(293478416267232);
+        let actual = NumbersToWords::<i64>::new(293478416267232);
         assert_eq!(format!("{actual}"), "two hundred and ninety three trillion four hundred and seventy eight billion four hundred and sixteen million two hundred and sixty seven thousand two hundred and thirty two");        
 
-        let actual = NumbersToWords::new(700000000000590);
+        let actual = NumbersToWords::<i64>::new(700000000000590);
         assert_eq!(format!("{actual}"), "seven hundred trillion and five hundred and ninety");     
     }
 
     #[test]
     fn displays_quadrillions() {
-        let actual = NumbersToWords::new(1_000_000_000_000_000);
+        let actual = NumbersToWords::<i64>::new(1_000_000_000_000_000);
         assert_eq!(format!("{actual}"), "one quadrillion");             
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn displays_negatives() {
+        let actual = NumbersToWords::<i64>::new(-1);
+        assert_eq!(format!("{actual}"), "minus one");
+
+        let actual = NumbersToWords::<i64>::new(-402);
+        assert_eq!(format!("{actual}"), "minus four hundred and two");
+
+        let actual = NumbersToWords::<i64>::new(-6047);
+        assert_eq!(format!("{actual}"), "minus six thousand and forty seven");
+
+        let actual = NumbersToWords::<i64>::new(-999_999_999_999_999);
+        assert_eq!(format!("{actual}"), "minus nine hundred and ninety nine trillion nine hundred and ninety nine billion nine hundred and ninety nine million nine hundred and ninety nine thousand nine hundred and ninety nine");
+
+        let actual = NumbersToWords::<i64>::new(-1_000_000_000_000_000);
+        assert_eq!(format!("{actual}"), "minus one quadrillion");
+    }
+
+    #[test]
+    fn displays_negatives_symmetrically() {
+        [1_i64, 19, 99, 301, 86047, 2000590, 700000000000590, 1_000_000_000_000_000]
+            .into_iter()
+            .for_each(|n| {
+                let positive = NumbersToWords::<i64>::new(n);
+                let negative = NumbersToWords::<i64>::new(-n);
+                assert_eq!(format!("{negative}"), format!("minus {positive}"));
+            });
+    }
+
+    #[test]
+    fn displays_negatives_with_configured_word() {
+        let actual = NumbersToWords::<i64>::new(-402).negative_word("negative");
+        assert_eq!(format!("{actual}"), "negative four hundred and two");
+
+        let actual = NumbersToWords::<i64>::new(402).negative_word("negative");
+        assert_eq!(format!("{actual}"), "four hundred and two");
+    }
+
+    #[test]
+    fn validates_negative_bounds() {
+        let low = -1_000_000_000_000_000;
+        let high = 1_000_000_000_000_000;
+
+        let actual = NumbersToWords::<i64>::validate("-402", low, high).unwrap();
+        assert_eq!(format!("{actual}"), "minus four hundred and two");
+
+        let actual = NumbersToWords::<i64>::validate("-1000000000000000", low, high).unwrap();
+        assert_eq!(format!("{actual}"), "minus one quadrillion");
+
+        let actual = NumbersToWords::<i64>::validate("-1000000000000001", low, high);
+        assert!(matches!(actual, Err(InputError::ValidationError { value: -1_000_000_000_000_001, .. })));
+
+        let actual = NumbersToWords::<i64>::validate("-1", 0, high);
+        assert!(matches!(actual, Err(InputError::ValidationError { value: -1, .. })));
+    }
+
+    #[test]
+    fn displays_past_quadrillion() {
+        let actual = NumbersToWords::<i64>::new(1_000_000_000_000_001);
+        assert_eq!(format!("{actual}"), "one quadrillion and one");
+
+        let actual = NumbersToWords::<i64>::new(999_999_000_000_000_000);
+        assert_eq!(format!("{actual}"), "nine hundred and ninety nine quadrillion nine hundred and ninety nine trillion");
+
+        let actual = NumbersToWords::<i64>::new(1_000_000_000_000_000_000);
+        assert_eq!(format!("{actual}"), "one quintillion");
+
+        let actual = NumbersToWords::<i64>::new(5_000_000_000_000_000_590);
+        assert_eq!(format!("{actual}"), "five quintillion and five hundred and ninety");
+    }
+
+    #[test]
+    fn displays_type_extremes() {
+        let actual = NumbersToWords::<i64>::new(i64::MAX);
+        assert_eq!(format!("{actual}"), "nine quintillion two hundred and twenty three quadrillion three hundred and seventy two trillion thirty six billion eight hundred and fifty four million seven hundred and seventy five thousand eight hundred and seven");
+
+        let actual = NumbersToWords::<i64>::new(i64::MIN);
+        assert_eq!(format!("{actual}"), "minus nine quintillion two hundred and twenty three quadrillion three hundred and seventy two trillion thirty six billion eight hundred and fifty four million seven hundred and seventy five thousand eight hundred and eight");
+
+        let actual = NumbersToWords::<u64>::new(u64::MAX);
+        assert_eq!(format!("{actual}"), "eighteen quintillion four hundred and forty six quadrillion seven hundred and forty four trillion seventy three billion seven hundred and nine million five hundred and fifty one thousand six hundred and fifteen");
+
+        let actual = NumbersToWords::<u128>::new(u128::MAX);
+        assert_eq!(format!("{actual}"), "three hundred and forty undecillion two hundred and eighty two decillion three hundred and sixty six nonillion nine hundred and twenty octillion nine hundred and thirty eight septillion four hundred and sixty three sextillion four hundred and sixty three quintillion three hundred and seventy four quadrillion six hundred and seven trillion four hundred and thirty one billion seven hundred and sixty eight million two hundred and eleven thousand four hundred and fifty five");
+
+        let actual = NumbersToWords::<i128>::new(i128::MIN);
+        assert_eq!(format!("{actual}"), "minus one hundred and seventy undecillion one hundred and forty one decillion one hundred and eighty three nonillion four hundred and sixty octillion four hundred and sixty nine septillion two hundred and thirty one sextillion seven hundred and thirty one quintillion six hundred and eighty seven quadrillion three hundred and three trillion seven hundred and fifteen billion eight hundred and eighty four million one hundred and five thousand seven hundred and twenty eight");
+    }
+
+    #[test]
+    fn displays_scale_words() {
+        let actual = NumbersToWords::<u128>::new(10_u128.pow(21));
+        assert_eq!(format!("{actual}"), "one sextillion");
+
+        let actual = NumbersToWords::<u128>::new(10_u128.pow(24));
+        assert_eq!(format!("{actual}"), "one septillion");
+
+        let actual = NumbersToWords::<u128>::new(10_u128.pow(27));
+        assert_eq!(format!("{actual}"), "one octillion");
+
+        let actual = NumbersToWords::<u128>::new(10_u128.pow(30));
+        assert_eq!(format!("{actual}"), "one nonillion");
+
+        let actual = NumbersToWords::<u128>::new(10_u128.pow(33));
+        assert_eq!(format!("{actual}"), "one decillion");
+
+        let actual = NumbersToWords::<u128>::new(10_u128.pow(36) + 7);
+        assert_eq!(format!("{actual}"), "one undecillion and seven");
+    }
+
+    #[test]
+    fn displays_every_integer_type() {
+        assert_eq!(format!("{}", NumbersToWords::<u8>::new(255)), "two hundred and fifty five");
+        assert_eq!(format!("{}", NumbersToWords::<i8>::new(-128)), "minus one hundred and twenty eight");
+        assert_eq!(format!("{}", NumbersToWords::<u16>::new(65_535)), "sixty five thousand five hundred and thirty five");
+        assert_eq!(format!("{}", NumbersToWords::<i16>::new(-32_768)), "minus thirty two thousand seven hundred and sixty eight");
+        assert_eq!(format!("{}", NumbersToWords::<u32>::new(4_000_000_000)), "four billion");
+        assert_eq!(format!("{}", NumbersToWords::<i32>::new(-2_000_000_000)), "minus two billion");
+        assert_eq!(format!("{}", NumbersToWords::<usize>::new(42)), "forty two");
+        assert_eq!(format!("{}", NumbersToWords::<isize>::new(-42).negative_word("negative")), "negative forty two");
+    }
+
+    #[test]
+    fn validates_wide_types() {
+        let actual = NumbersToWords::<u64>::validate(u64::MAX.to_string(), 0, u64::MAX).unwrap();
+        assert_eq!(format!("{actual}"), format!("{}", NumbersToWords::<u64>::new(u64::MAX)));
+
+        let actual = NumbersToWords::<u128>::validate("1000000000000000000000000000000000000", 0, u128::MAX).unwrap();
+        assert_eq!(format!("{actual}"), "one undecillion");
+
+        let actual = NumbersToWords::<i128>::validate("-5", i128::MIN, i128::MAX).unwrap();
+        assert_eq!(format!("{actual}"), "minus five");
+
+        let actual = NumbersToWords::<u64>::validate("-5", 0, u64::MAX);
+        assert!(matches!(actual, Err(InputError::ParseError { position: 0, .. })));
+    }
+
+    #[test]
+    fn displays_casing() {
+        let cased = |casing| format!("{}", NumbersToWords::new(1_200_i64).casing(casing));
+
+        assert_eq!(cased(Casing::Lower), "one thousand two hundred");
+        assert_eq!(cased(Casing::Sentence), "One thousand two hundred");
+        assert_eq!(cased(Casing::Title), "One Thousand Two Hundred");
+        assert_eq!(cased(Casing::Upper), "ONE THOUSAND TWO HUNDRED");
+
+        let actual = NumbersToWords::new(-42_i8).options(RenderOptions::BRITISH).casing(Casing::Title);
+        assert_eq!(format!("{actual}"), "Minus Forty-Two");
+
+        let actual = NumbersToWords::new(21_u8).ordinal().casing(Casing::Sentence);
+        assert_eq!(format!("{actual}"), "Twenty first");
+
+        let actual = NumbersToWords::new(17_u8).language(French).casing(Casing::Upper);
+        assert_eq!(format!("{actual}"), "DIX-SEPT");
+    }
+
+    #[test]
+    fn displays_casing_across_parts() {
+        let actual = NumbersToWords::new(Year(1984)).casing(Casing::Sentence);
+        assert_eq!(format!("{actual}"), "Nineteen eighty four");
+
+        let actual = NumbersToWords::new(Date::new(4, 7).unwrap()).casing(Casing::Title);
+        assert_eq!(format!("{actual}"), "The Fourth of July");
+
+        let actual = NumbersToWords::new(Money::new(120_050, Currency::USD)).casing(Casing::Title);
+        assert_eq!(format!("{actual}"), "One Thousand Two Hundred Dollars and Fifty Cents");
+    }
+
+    #[test]
+    fn displays_terminators() {
+        let actual = NumbersToWords::new(Money::new(120_000, Currency::GBP)).casing(Casing::Upper).terminator("only");
+        assert_eq!(format!("{actual}"), "ONE THOUSAND TWO HUNDRED POUNDS ONLY");
+
+        let actual = NumbersToWords::new(250_u16).terminator("exactly");
+        assert_eq!(format!("{actual}"), "two hundred and fifty exactly");
+
+        let actual = NumbersToWords::new(3_u8).ordinal().terminator("only").casing(Casing::Sentence);
+        assert_eq!(format!("{actual}"), "Third only");
+    }
+}
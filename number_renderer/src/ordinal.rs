@@ -0,0 +1,223 @@
+use alloc::string::ToString;
+use core::{fmt::Display, str::FromStr};
+
+use crate::{
+    digit_string::DigitString,
+    numbers_to_words::{NumbersToWords, Render},
+    validation_errors::{ParseDigitsError, ParsePosition},
+};
+
+// A number read as a position, "121st" on its own or "one hundred and twenty first" as words
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Ordinal<N>(pub N);
+
+// Writes the ordinal form of a single cardinal word, "one" as "first" or "twenty" as "twentieth"
+pub(crate) fn write_ordinal_word<W: core::fmt::Write>(f: &mut W, word: &str) -> core::fmt::Result {
+    match word {
+        "one" => write!(f, "first"),
+        "two" => write!(f, "second"),
+        "three" => write!(f, "third"),
+        "five" => write!(f, "fifth"),
+        "eight" => write!(f, "eighth"),
+        "nine" => write!(f, "ninth"),
+        "twelve" => write!(f, "twelfth"),
+        tens if tens.ends_with('y') => write!(f, "{}ieth", &tens[..tens.len() - 1]),
+        word => write!(f, "{}th", word),
+    }
+}
+
+fn suffix(digits: &str) -> &'static str {
+    let tail = digits
+        .bytes()
+        .rev()
+        .take_while(u8::is_ascii_digit)
+        .take(2)
+        .fold((0, 1), |(acc, place), digit| (acc + (digit - b'0') as u32 * place, place * 10))
+        .0;
+
+    match (tail % 10, tail % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    }
+}
+
+impl<N: Display> Display for Ordinal<N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let number = self.0.to_string();
+        write!(f, "{}{}", number, suffix(&number))
+    }
+}
+
+impl<N> FromStr for Ordinal<N>
+where
+    N: FromStr,
+    N::Err: ParsePosition,
+{
+    type Err = ParseDigitsError;
+
+    // Accepts the plain number or the number with its correct suffix, "21" or "21st"
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let number = s.trim_end_matches(|c: char| c.is_ascii_alphabetic());
+        let written = &s[number.len()..];
+
+        let value = number.parse::<N>().map_err(|err| ParseDigitsError {
+            position: err.position(number),
+        })?;
+
+        if !written.is_empty() && written != suffix(number) {
+            return Err(ParseDigitsError {
+                position: number.len(),
+            });
+        }
+
+        Ok(Ordinal(value))
+    }
+}
+
+macro_rules! display_ordinal {
+    ($($t:ty),*) => {
+        $(
+            impl Render for NumbersToWords<Ordinal<$t>> {
+                fn render(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    write!(f, "{}", self.with_number(self.number.0).with_ordinal(true))
+                }
+            }
+        )*
+    };
+}
+
+display_ordinal!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl Render for NumbersToWords<Ordinal<DigitString>> {
+    fn render(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.number.0.is_negative() {
+            write!(f, "{} ", self.negative())?;
+        }
+
+        self.number.0.write_magnitude(f, true, self.settings.options)
+    }
+}
+
+impl<N> NumbersToWords<N> {
+    // Reads the number as a position, "first" rather than "one"
+    pub fn ordinal(self) -> NumbersToWords<Ordinal<N>> {
+        self.map(Ordinal).with_ordinal(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{format, string::{String, ToString}};
+
+    use super::Ordinal;
+    use crate::{
+        digit_string::DigitString, numbers_to_words::NumbersToWords,
+        validation_errors::{InputError, ParseDigitsError}, validator::Validator,
+    };
+
+    fn words(number: i64) -> String {
+        format!("{}", NumbersToWords::new(Ordinal(number)))
+    }
+
+    #[test]
+    fn displays_small_ordinals() {
+        assert_eq!(words(0), "zeroth");
+        assert_eq!(words(1), "first");
+        assert_eq!(words(2), "second");
+        assert_eq!(words(3), "third");
+        assert_eq!(words(4), "fourth");
+        assert_eq!(words(5), "fifth");
+        assert_eq!(words(8), "eighth");
+        assert_eq!(words(9), "ninth");
+        assert_eq!(words(11), "eleventh");
+        assert_eq!(words(12), "twelfth");
+        assert_eq!(words(13), "thirteenth");
+        assert_eq!(words(20), "twentieth");
+        assert_eq!(words(22), "twenty second");
+        assert_eq!(words(40), "fortieth");
+        assert_eq!(words(99), "ninety ninth");
+    }
+
+    #[test]
+    fn displays_large_ordinals() {
+        assert_eq!(words(100), "one hundredth");
+        assert_eq!(words(121), "one hundred and twenty first");
+        assert_eq!(words(300), "three hundredth");
+        assert_eq!(words(1_000), "one thousandth");
+        assert_eq!(words(6047), "six thousand and forty seventh");
+        assert_eq!(words(1_000_000), "one millionth");
+        assert_eq!(words(2000590), "two million and five hundred and ninetieth");
+        assert_eq!(words(1_000_000_000_000_000), "one quadrillionth");
+        assert_eq!(words(-3), "minus third");
+        assert!(words(i64::MAX).ends_with("seven hundred and seventy five thousand eight hundred and seventh"));
+    }
+
+    #[test]
+    fn displays_ordinals_of_every_type() {
+        assert_eq!(format!("{}", NumbersToWords::new(Ordinal(255_u8))), "two hundred and fifty fifth");
+        assert_eq!(format!("{}", NumbersToWords::new(10_u128.pow(36)).ordinal()), "one undecillionth");
+        assert_eq!(format!("{}", NumbersToWords::new(12_usize).ordinal()), "twelfth");
+
+        let huge = format!("1{}", "0".repeat(3003)).parse::<DigitString>().unwrap();
+        assert_eq!(format!("{}", NumbersToWords::new(huge).ordinal()), "one millinillionth");
+
+        let digits = "2000021".parse::<DigitString>().unwrap();
+        assert_eq!(format!("{}", NumbersToWords::new(digits).ordinal()), "two million and twenty first");
+
+        let zero = "0".parse::<DigitString>().unwrap();
+        assert_eq!(format!("{}", NumbersToWords::new(zero).ordinal()), "zeroth");
+    }
+
+    #[test]
+    fn matches_digit_string_ordinals() {
+        [1_i64, 12, 100, 121, 6047, 2000590, 1_002_000_000, i64::MAX].iter().for_each(|n| {
+            let digits = n.to_string().parse::<DigitString>().unwrap();
+            assert_eq!(format!("{}", NumbersToWords::new(digits).ordinal()), words(*n));
+        });
+    }
+
+    #[test]
+    fn abbreviates_ordinals() {
+        let abbreviations = [
+            (1, "1st"),
+            (2, "2nd"),
+            (3, "3rd"),
+            (4, "4th"),
+            (11, "11th"),
+            (12, "12th"),
+            (13, "13th"),
+            (21, "21st"),
+            (22, "22nd"),
+            (101, "101st"),
+            (111, "111th"),
+            (112, "112th"),
+            (1_000_000, "1000000th"),
+            (-1, "-1st"),
+        ];
+
+        abbreviations
+            .iter()
+            .for_each(|(n, expected)| assert_eq!(Ordinal(*n).to_string(), *expected));
+    }
+
+    #[test]
+    fn parses_ordinals() {
+        assert_eq!("21st".parse::<Ordinal<i64>>(), Ok(Ordinal(21)));
+        assert_eq!("21".parse::<Ordinal<i64>>(), Ok(Ordinal(21)));
+        assert_eq!("112th".parse::<Ordinal<u32>>(), Ok(Ordinal(112)));
+        assert_eq!("21nd".parse::<Ordinal<i64>>(), Err(ParseDigitsError { position: 2 }));
+        assert_eq!("2x1st".parse::<Ordinal<i64>>(), Err(ParseDigitsError { position: 1 }));
+    }
+
+    #[test]
+    fn validates_ordinals() {
+        let actual = NumbersToWords::<Ordinal<i64>>::validate("121st", Ordinal(1), Ordinal(1000)).unwrap();
+        assert_eq!(format!("{actual}"), "one hundred and twenty first");
+
+        let actual = NumbersToWords::<Ordinal<i64>>::validate("0th", Ordinal(1), Ordinal(1000));
+        assert!(matches!(actual, Err(InputError::ValidationError { value: Ordinal(0), .. })));
+    }
+}
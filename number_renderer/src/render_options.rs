@@ -0,0 +1,168 @@
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AndPlacement {
+    // Before any remainder smaller than the next scale down, "two million and five hundred and ninety"
+    Remainder,
+    // Only before a final part below a hundred, "two million five hundred and ninety"
+    Final,
+}
+
+// How English numbers are read, other languages follow their own rules
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RenderOptions {
+    // "one hundred and one" rather than "one hundred one"
+    pub and: bool,
+    pub and_placement: AndPlacement,
+    // "ninety-nine" rather than "ninety nine"
+    pub hyphenate: bool,
+    // "one milliard" and "one billion" for 10^9 and 10^12, rather than "one billion" and "one trillion"
+    pub long_scale: bool,
+}
+
+impl RenderOptions {
+    pub const BRITISH: RenderOptions = RenderOptions {
+        and: true,
+        and_placement: AndPlacement::Final,
+        hyphenate: true,
+        long_scale: false,
+    };
+
+    pub const AMERICAN: RenderOptions = RenderOptions {
+        and: false,
+        and_placement: AndPlacement::Final,
+        hyphenate: true,
+        long_scale: false,
+    };
+
+    pub(crate) fn and(&self) -> &'static str {
+        if self.and {
+            "and "
+        } else {
+            ""
+        }
+    }
+
+    pub(crate) fn tens_separator(&self) -> &'static str {
+        if self.hyphenate {
+            "-"
+        } else {
+            " "
+        }
+    }
+}
+
+impl Default for RenderOptions {
+    fn default() -> Self {
+        RenderOptions {
+            and: true,
+            and_placement: AndPlacement::Remainder,
+            hyphenate: false,
+            long_scale: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{format, string::{String, ToString}};
+
+    use super::{AndPlacement, RenderOptions};
+    use crate::{digit_string::DigitString, numbers_to_words::NumbersToWords};
+
+    fn words(number: i64, options: RenderOptions) -> String {
+        format!("{}", NumbersToWords::new(number).options(options))
+    }
+
+    fn digits(number: i64, options: RenderOptions) -> String {
+        let digits = number.to_string().parse::<DigitString>().unwrap();
+        format!("{}", NumbersToWords::new(digits).options(options))
+    }
+
+    #[test]
+    fn keeps_current_output_by_default() {
+        let default = RenderOptions::default();
+
+        assert_eq!(words(2000590, default), "two million and five hundred and ninety");
+        assert_eq!(words(99, default), "ninety nine");
+        assert_eq!(words(1_000_000_000, default), "one billion");
+        assert_eq!(words(2000590, default), format!("{}", NumbersToWords::<i64>::new(2000590)));
+    }
+
+    #[test]
+    fn places_and_before_final_part() {
+        let options = RenderOptions {
+            and_placement: AndPlacement::Final,
+            ..RenderOptions::default()
+        };
+
+        assert_eq!(words(2000590, options), "two million five hundred and ninety");
+        assert_eq!(words(1_000_005, options), "one million and five");
+        assert_eq!(words(1_002_000_005, options), "one billion two million and five");
+        assert_eq!(words(6047, options), "six thousand and forty seven");
+        assert_eq!(words(900_100, options), "nine hundred thousand one hundred");
+    }
+
+    #[test]
+    fn omits_and() {
+        let options = RenderOptions {
+            and: false,
+            ..RenderOptions::default()
+        };
+
+        assert_eq!(words(301, options), "three hundred one");
+        assert_eq!(words(2000590, options), "two million five hundred ninety");
+        assert_eq!(words(-6047, options), "minus six thousand forty seven");
+    }
+
+    #[test]
+    fn hyphenates_tens() {
+        let options = RenderOptions {
+            hyphenate: true,
+            ..RenderOptions::default()
+        };
+
+        assert_eq!(words(99, options), "ninety-nine");
+        assert_eq!(words(90, options), "ninety");
+        assert_eq!(words(21_421, options), "twenty-one thousand four hundred and twenty-one");
+        assert_eq!(format!("{}", NumbersToWords::new(42_u32).options(options).ordinal()), "forty-second");
+    }
+
+    #[test]
+    fn names_long_scale() {
+        let options = RenderOptions {
+            long_scale: true,
+            ..RenderOptions::default()
+        };
+
+        assert_eq!(words(1_000_000, options), "one million");
+        assert_eq!(words(1_000_000_000, options), "one milliard");
+        assert_eq!(words(2_000_000_000_000, options), "two billion");
+        assert_eq!(words(3_000_000_000_000_000, options), "three billiard");
+        assert_eq!(format!("{}", NumbersToWords::new(10_u128.pow(36)).options(options)), "one sextillion");
+    }
+
+    #[test]
+    fn names_british_and_american_styles() {
+        assert_eq!(words(2000590, RenderOptions::BRITISH), "two million five hundred and ninety");
+        assert_eq!(words(2000599, RenderOptions::AMERICAN), "two million five hundred ninety-nine");
+    }
+
+    #[test]
+    fn matches_digit_strings() {
+        let styles = [
+            RenderOptions::default(),
+            RenderOptions::BRITISH,
+            RenderOptions::AMERICAN,
+            RenderOptions {
+                long_scale: true,
+                ..RenderOptions::default()
+            },
+        ];
+
+        let numbers = [0_i64, 21, 301, 6047, 900_100, 1_000_005, 2000590, 1_002_000_005, 3_000_000_000_021, i64::MAX];
+        styles.iter().for_each(|options| {
+            numbers
+                .iter()
+                .for_each(|n| assert_eq!(digits(*n, *options), words(*n, *options)));
+        });
+    }
+}
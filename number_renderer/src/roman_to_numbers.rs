@@ -0,0 +1,203 @@
+use alloc::{string::String, vec::Vec};
+
+use crate::{
+    numbers_to_roman::{write_roman, MAX_VINCULUM, OVERLINE},
+    validation_errors::ParseRomanError,
+};
+
+fn value(numeral: char) -> Option<u32> {
+    match numeral.to_ascii_uppercase() {
+        'I' => Some(1),
+        'V' => Some(5),
+        'X' => Some(10),
+        'L' => Some(50),
+        'C' => Some(100),
+        'D' => Some(500),
+        'M' => Some(1000),
+        _ => None,
+    }
+}
+
+// Reads Roman numerals in either case back into a number, a vinculum multiplying by a thousand
+pub struct RomanToNumbers<'a> {
+    numerals: &'a str,
+    strict: bool,
+}
+
+impl<'a> RomanToNumbers<'a> {
+    pub fn new(numerals: &'a str) -> Self {
+        RomanToNumbers {
+            numerals,
+            strict: true,
+        }
+    }
+
+    // Adds or subtracts each numeral the way it is read, taking "IIII" as 4 and "IC" as 99
+    pub fn lenient(mut self) -> Self {
+        self.strict = false;
+        self
+    }
+
+    // Each numeral's value and byte position
+    fn values(&self) -> Result<Vec<(u32, usize)>, ParseRomanError> {
+        let mut values: Vec<(u32, usize)> = Vec::new();
+        let mut overlined = false;
+        for (position, c) in self.numerals.char_indices() {
+            match (c, values.last_mut()) {
+                (OVERLINE, Some((value, _))) if !overlined => {
+                    *value *= 1000;
+                    overlined = true;
+                }
+                _ => {
+                    let value = value(c).ok_or(ParseRomanError::InvalidNumeral { position })?;
+                    values.push((value, position));
+                    overlined = false;
+                }
+            }
+        }
+
+        match values.is_empty() {
+            true => Err(ParseRomanError::InvalidNumeral { position: 0 }),
+            false => Ok(values),
+        }
+    }
+
+    pub fn parse(&self) -> Result<u32, ParseRomanError> {
+        let values = self.values()?;
+
+        // A numeral before a larger one is taken away from it, as in "IV"
+        let total = values.iter().enumerate().fold(0_i64, |total, (index, (value, _))| {
+            match values.get(index + 1) {
+                Some((next, _)) if next > value => total - *value as i64,
+                _ => total + *value as i64,
+            }
+        });
+
+        let number = u32::try_from(total)
+            .ok()
+            .filter(|number| (1..=MAX_VINCULUM).contains(number))
+            .ok_or(ParseRomanError::OutOfRange { position: 0 })?;
+
+        if self.strict {
+            self.canonical(number)?;
+        }
+
+        Ok(number)
+    }
+
+    // Numerals are only canonical when they match how the number would be written
+    fn canonical(&self, number: u32) -> Result<(), ParseRomanError> {
+        let mut expected = String::new();
+        write_roman(&mut expected, number).map_err(|_| ParseRomanError::OutOfRange { position: 0 })?;
+
+        let mut expected = expected.chars();
+        for (position, c) in self.numerals.char_indices() {
+            if expected.next() != Some(c.to_ascii_uppercase()) {
+                return Err(ParseRomanError::NonCanonical { position });
+            }
+        }
+
+        match expected.next() {
+            Some(_) => Err(ParseRomanError::NonCanonical {
+                position: self.numerals.len(),
+            }),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::format;
+    use proptest::prelude::*;
+
+    use super::RomanToNumbers;
+    use crate::{numbers_to_roman::NumbersToRoman, validation_errors::ParseRomanError};
+
+    fn parse(numerals: &str) -> Result<u32, ParseRomanError> {
+        RomanToNumbers::new(numerals).parse()
+    }
+
+    fn lenient(numerals: &str) -> Result<u32, ParseRomanError> {
+        RomanToNumbers::new(numerals).lenient().parse()
+    }
+
+    #[test]
+    fn parses_numerals() {
+        assert_eq!(parse("I"), Ok(1));
+        assert_eq!(parse("IV"), Ok(4));
+        assert_eq!(parse("IX"), Ok(9));
+        assert_eq!(parse("XLIX"), Ok(49));
+        assert_eq!(parse("CDXLIV"), Ok(444));
+        assert_eq!(parse("MCMXCIV"), Ok(1994));
+        assert_eq!(parse("MMMCMXCIX"), Ok(3999));
+    }
+
+    #[test]
+    fn parses_lowercase() {
+        assert_eq!(parse("iv"), Ok(4));
+        assert_eq!(parse("xii"), Ok(12));
+        assert_eq!(parse("McMxCiV"), Ok(1994));
+    }
+
+    #[test]
+    fn parses_vinculum() {
+        assert_eq!(parse("I\u{305}V\u{305}"), Ok(4000));
+        assert_eq!(parse("V\u{305}I"), Ok(5001));
+        assert_eq!(parse("M\u{305}"), Ok(1_000_000));
+        assert_eq!(parse("M\u{305}M\u{305}M\u{305}C\u{305}M\u{305}X\u{305}C\u{305}I\u{305}X\u{305}CMXCIX"), Ok(3_999_999));
+    }
+
+    #[test]
+    fn rejects_non_canonical_forms() {
+        assert_eq!(parse("IIII"), Err(ParseRomanError::NonCanonical { position: 1 }));
+        assert_eq!(parse("VIIII"), Err(ParseRomanError::NonCanonical { position: 0 }));
+        assert_eq!(parse("IC"), Err(ParseRomanError::NonCanonical { position: 0 }));
+        assert_eq!(parse("VX"), Err(ParseRomanError::NonCanonical { position: 1 }));
+        assert_eq!(parse("XM"), Err(ParseRomanError::NonCanonical { position: 0 }));
+        assert_eq!(parse("MMMM"), Err(ParseRomanError::NonCanonical { position: 0 }));
+        assert_eq!(parse("I\u{305}"), Err(ParseRomanError::NonCanonical { position: 0 }));
+        assert_eq!(parse("DD"), Err(ParseRomanError::NonCanonical { position: 0 }));
+    }
+
+    #[test]
+    fn parses_non_canonical_forms_leniently() {
+        assert_eq!(lenient("IIII"), Ok(4));
+        assert_eq!(lenient("VIIII"), Ok(9));
+        assert_eq!(lenient("IC"), Ok(99));
+        assert_eq!(lenient("MMMM"), Ok(4000));
+        assert_eq!(lenient("I\u{305}"), Ok(1000));
+        assert_eq!(lenient("MCMXCIV"), Ok(1994));
+    }
+
+    #[test]
+    fn points_to_invalid_numerals() {
+        assert_eq!(parse(""), Err(ParseRomanError::InvalidNumeral { position: 0 }));
+        assert_eq!(parse("XIIA"), Err(ParseRomanError::InvalidNumeral { position: 3 }));
+        assert_eq!(parse(" XII"), Err(ParseRomanError::InvalidNumeral { position: 0 }));
+        assert_eq!(parse("\u{305}I"), Err(ParseRomanError::InvalidNumeral { position: 0 }));
+        assert_eq!(parse("I\u{305}\u{305}"), Err(ParseRomanError::InvalidNumeral { position: 3 }));
+        assert_eq!(lenient("M\u{305}M\u{305}M\u{305}M\u{305}"), Err(ParseRomanError::OutOfRange { position: 0 }));
+    }
+
+    #[test]
+    fn displays_errors() {
+        assert_eq!(format!("{}", ParseRomanError::InvalidNumeral { position: 3 }), "invalid numeral at position 3");
+        assert_eq!(format!("{}", ParseRomanError::NonCanonical { position: 1 }), "numerals are not written the standard way from position 1");
+    }
+
+    proptest! {
+        #[test]
+        fn round_trips(number in 1_u32..=3999) {
+            let numerals = format!("{}", NumbersToRoman::new(number));
+            prop_assert_eq!(parse(&numerals), Ok(number));
+            prop_assert_eq!(parse(&numerals.to_lowercase()), Ok(number));
+        }
+
+        #[test]
+        fn round_trips_vinculum(number in 1_u32..=3_999_999) {
+            let numerals = format!("{}", NumbersToRoman::new(number).vinculum());
+            prop_assert_eq!(parse(&numerals), Ok(number));
+        }
+    }
+}
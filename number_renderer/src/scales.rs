@@ -0,0 +1,168 @@
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+};
+
+// Conway–Wechsler latin prefixes, paired with the markers that change the unit before them
+const UNITS: [&str; 10] = ["", "un", "duo", "tre", "quattuor", "quinqua", "se", "septe", "octo", "nove"];
+
+const TENS: [(&str, &str); 10] = [
+    ("", ""),
+    ("deci", "n"),
+    ("viginti", "ms"),
+    ("triginta", "ns"),
+    ("quadraginta", "ns"),
+    ("quinquaginta", "ns"),
+    ("sexaginta", "n"),
+    ("septuaginta", "n"),
+    ("octoginta", "mx"),
+    ("nonaginta", ""),
+];
+
+const HUNDREDS: [(&str, &str); 10] = [
+    ("", ""),
+    ("centi", "nx"),
+    ("ducenti", "n"),
+    ("trecenti", "ns"),
+    ("quadringenti", "ns"),
+    ("quingenti", "ns"),
+    ("sescenti", "n"),
+    ("septingenti", "n"),
+    ("octingenti", "mx"),
+    ("nongenti", ""),
+];
+
+const SMALL: [&str; 10] = ["n", "m", "b", "tr", "quadr", "quint", "sext", "sept", "oct", "non"];
+
+fn unit_prefix(units: usize, marker: &str) -> String {
+    let unit = UNITS[units];
+    let suffix = match units {
+        3 if marker.contains('s') || marker.contains('x') => "s",
+        6 if marker.contains('s') => "s",
+        6 if marker.contains('x') => "x",
+        7 | 9 if marker.contains('m') => "m",
+        7 | 9 if marker.contains('n') => "n",
+        _ => "",
+    };
+
+    format!("{}{}", unit, suffix)
+}
+
+fn illi(group: usize) -> String {
+    if group < 10 {
+        return format!("{}illi", SMALL[group]);
+    }
+
+    let (units, tens, hundreds) = (group % 10, group / 10 % 10, group / 100);
+    let (tens_prefix, tens_marker) = TENS[tens];
+    let (hundreds_prefix, hundreds_marker) = HUNDREDS[hundreds];
+    let marker = if tens > 0 { tens_marker } else { hundreds_marker };
+
+    let prefix = format!("{}{}{}", unit_prefix(units, marker), tens_prefix, hundreds_prefix);
+
+    // The final vowel of the latin prefix is dropped in favour of "illi"
+    format!("{}illi", prefix.trim_end_matches(['a', 'i']))
+}
+
+// The name of 10^(3n + 3), so 1 is "million", 2 is "billion" and 1000 is "millinillion"
+pub fn illion(n: usize) -> String {
+    let mut groups = vec![];
+    let mut rest = n;
+    while rest > 0 {
+        groups.push(rest % 1000);
+        rest /= 1000;
+    }
+
+    let prefixes = groups
+        .iter()
+        .rev()
+        .map(|group| illi(*group))
+        .collect::<String>();
+
+    format!("{}on", prefixes)
+}
+
+// The name of 1000^index, empty for the units
+pub fn scale_name(index: usize) -> String {
+    match index {
+        0 => String::new(),
+        1 => "thousand".to_string(),
+        n => illion(n - 1),
+    }
+}
+
+// The long scale name of 1000^index, where each "-illion" is a million times the last
+// and the "-illiard" between them is a thousand times that
+pub fn long_scale_name(index: usize) -> String {
+    match index {
+        0 | 1 => scale_name(index),
+        n if n % 2 == 0 => illion(n / 2),
+        n => format!("{}iard", illion(n / 2).trim_end_matches("ion")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{illion, long_scale_name};
+
+    #[test]
+    fn names_short_scale() {
+        let expected = [
+            "million",
+            "billion",
+            "trillion",
+            "quadrillion",
+            "quintillion",
+            "sextillion",
+            "septillion",
+            "octillion",
+            "nonillion",
+            "decillion",
+            "undecillion",
+        ];
+
+        expected
+            .iter()
+            .enumerate()
+            .for_each(|(n, name)| assert_eq!(illion(n + 1), *name));
+    }
+
+    #[test]
+    fn names_tens_and_hundreds() {
+        assert_eq!(illion(12), "duodecillion");
+        assert_eq!(illion(13), "tredecillion");
+        assert_eq!(illion(16), "sedecillion");
+        assert_eq!(illion(17), "septendecillion");
+        assert_eq!(illion(19), "novendecillion");
+        assert_eq!(illion(20), "vigintillion");
+        assert_eq!(illion(21), "unvigintillion");
+        assert_eq!(illion(23), "tresvigintillion");
+        assert_eq!(illion(27), "septemvigintillion");
+        assert_eq!(illion(36), "sestrigintillion");
+        assert_eq!(illion(86), "sexoctogintillion");
+        assert_eq!(illion(100), "centillion");
+        assert_eq!(illion(103), "trescentillion");
+        assert_eq!(illion(106), "sexcentillion");
+        assert_eq!(illion(999), "novenonagintanongentillion");
+    }
+
+    #[test]
+    fn names_thousands_of_illions() {
+        assert_eq!(illion(1000), "millinillion");
+        assert_eq!(illion(1001), "millimillion");
+        assert_eq!(illion(1010), "millidecillion");
+        assert_eq!(illion(2000), "billinillion");
+        assert_eq!(illion(1_000_000), "millinillinillion");
+    }
+
+    #[test]
+    fn names_long_scale() {
+        let expected = ["", "thousand", "million", "milliard", "billion", "billiard", "trillion", "trilliard", "quadrillion"];
+
+        expected
+            .iter()
+            .enumerate()
+            .for_each(|(index, name)| assert_eq!(long_scale_name(index), *name));
+    }
+}
@@ -0,0 +1,344 @@
+use alloc::{format, string::String};
+use core::fmt::{self, Display};
+
+use serde::{
+    de::{self, Visitor},
+    ser::SerializeStruct,
+    Deserializer, Serialize, Serializer,
+};
+
+use crate::{
+    approximate::Approximate,
+    calendar::{Date, Time, Year},
+    counted::Counted,
+    currency::Money,
+    decimal::Decimal,
+    digit_string::DigitString,
+    digits::Digits,
+    languages::Language,
+    numbers_to_words::{NumbersToWords, Render},
+    ordinal::Ordinal,
+    validation_errors::InputError,
+    validator::{Rule, Rules, Validator},
+};
+
+// The value alongside its words, {"value": 42, "words": "forty two"}
+impl<N: Serialize, L: Language> Serialize for NumbersToWords<N, L>
+where
+    Self: Render,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("NumbersToWords", 2)?;
+        state.serialize_field("value", &self.number)?;
+        state.serialize_field("words", &format_args!("{}", self))?;
+        state.end()
+    }
+}
+
+// Written out as strings, as a JSON number would lose their precision and leading zeros
+macro_rules! serialize_as_string {
+    ($($t:ty),*) => {
+        $(
+            impl Serialize for $t {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serializer.collect_str(self)
+                }
+            }
+        )*
+    };
+}
+
+serialize_as_string!(Decimal, DigitString, Digits, Money);
+
+impl<N: Serialize> Serialize for Ordinal<N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+impl Serialize for Year {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+// The exact value, rather than the rounded one the words give
+impl<N: Serialize> Serialize for Approximate<N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.number.serialize(serializer)
+    }
+}
+
+impl<N: Serialize> Serialize for Counted<N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.count.serialize(serializer)
+    }
+}
+
+// In ISO 8601 form, "09:05"
+impl Serialize for Time {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&format_args!("{:02}:{:02}", self.hour, self.minute))
+    }
+}
+
+// In ISO 8601 form, "1984-03-21", or "--03-21" without a year
+impl Serialize for Date {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.year {
+            Some(Year(year)) => serializer.collect_str(&format_args!("{:04}-{:02}-{:02}", year, self.month, self.day)),
+            None => serializer.collect_str(&format_args!("--{:02}-{:02}", self.month, self.day)),
+        }
+    }
+}
+
+// Tagged by kind, with the bounds the rule set, {"kind": "range", "low": 1, "high": 10}
+impl<N: Serialize> Serialize for Rule<N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Rule::Range { low, high } => {
+                let mut state = serializer.serialize_struct("Rule", 3)?;
+                state.serialize_field("kind", "range")?;
+                state.serialize_field("low", low)?;
+                state.serialize_field("high", high)?;
+                state.end()
+            }
+            Rule::Exclude(value) => {
+                let mut state = serializer.serialize_struct("Rule", 2)?;
+                state.serialize_field("kind", "exclude")?;
+                state.serialize_field("value", value)?;
+                state.end()
+            }
+            Rule::MultipleOf(step) => {
+                let mut state = serializer.serialize_struct("Rule", 2)?;
+                state.serialize_field("kind", "multiple_of")?;
+                state.serialize_field("step", step)?;
+                state.end()
+            }
+            Rule::Custom(name) => {
+                let mut state = serializer.serialize_struct("Rule", 2)?;
+                state.serialize_field("kind", "custom")?;
+                state.serialize_field("name", name)?;
+                state.end()
+            }
+        }
+    }
+}
+
+// Tagged by kind, with the input and the message the error displays as
+impl<N: Display + Serialize> Serialize for InputError<N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            InputError::ParseError { input, position } => {
+                let mut state = serializer.serialize_struct("InputError", 4)?;
+                state.serialize_field("kind", "parse_error")?;
+                state.serialize_field("input", input)?;
+                state.serialize_field("position", position)?;
+                state.serialize_field("message", &format_args!("{}", self))?;
+                state.end()
+            }
+            InputError::ValidationError { input, value, rule } => {
+                let mut state = serializer.serialize_struct("InputError", 5)?;
+                state.serialize_field("kind", "validation_error")?;
+                state.serialize_field("input", input)?;
+                state.serialize_field("value", value)?;
+                state.serialize_field("rule", rule)?;
+                state.serialize_field("message", &format_args!("{}", self))?;
+                state.end()
+            }
+        }
+    }
+}
+
+// Takes numbers as JSON strings or numbers, handing them to the rules as text
+struct InputVisitor;
+
+impl Visitor<'_> for InputVisitor {
+    type Value = String;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a number or a string holding one")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<String, E> {
+        Ok(String::from(value))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<String, E> {
+        Ok(format!("{}", value))
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<String, E> {
+        Ok(format!("{}", value))
+    }
+
+    fn visit_i128<E: de::Error>(self, value: i128) -> Result<String, E> {
+        Ok(format!("{}", value))
+    }
+
+    fn visit_u128<E: de::Error>(self, value: u128) -> Result<String, E> {
+        Ok(format!("{}", value))
+    }
+
+    fn visit_f64<E: de::Error>(self, value: f64) -> Result<String, E> {
+        Ok(format!("{}", value))
+    }
+}
+
+impl<V: Validator> Rules<V>
+where
+    V::Error: Display,
+{
+    // Validates while deserializing, with a broken rule or bad input failing as a
+    // deserialization error. Being a method it needs wrapping in a function of its own
+    // to go behind #[serde(deserialize_with)], see validated for one without rules
+    pub fn deserialize<'de, D: Deserializer<'de>>(&self, deserializer: D) -> Result<V::Output, D::Error> {
+        let input = deserializer.deserialize_any(InputVisitor)?;
+        self.validate(input).map_err(de::Error::custom)
+    }
+}
+
+// Parses while deserializing, for #[serde(deserialize_with = "validated::<NumbersToWords<i64>, _>")],
+// with bad input failing as a deserialization error
+pub fn validated<'de, V, D>(deserializer: D) -> Result<V::Output, D::Error>
+where
+    V: Validator,
+    V::Error: Display,
+    D: Deserializer<'de>,
+{
+    V::rules().deserialize(deserializer)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{format, string::ToString};
+
+    use serde::{Deserialize, Deserializer};
+    use serde_json::json;
+
+    use super::validated;
+    use crate::{
+        approximate::Approximate,
+        calendar::{Date, Time, Year},
+        counted::{Counted, Noun},
+        currency::{Currency, Money},
+        decimal::Decimal,
+        digits::Digits,
+        numbers_to_words::NumbersToWords,
+        ordinal::Ordinal,
+        validator::Validator,
+    };
+
+    fn amount<'de, D: Deserializer<'de>>(deserializer: D) -> Result<NumbersToWords<i64>, D::Error> {
+        NumbersToWords::<i64>::rules().range(0, 1_000).exclude(13).deserialize(deserializer)
+    }
+
+    #[test]
+    fn serializes_value_and_words() {
+        let actual = serde_json::to_value(NumbersToWords::new(42_i64)).unwrap();
+        assert_eq!(actual, json!({ "value": 42, "words": "forty two" }));
+
+        let actual = serde_json::to_value(NumbersToWords::new(Ordinal(3_u8))).unwrap();
+        assert_eq!(actual, json!({ "value": 3, "words": "third" }));
+
+        let actual = serde_json::to_value(NumbersToWords::new("0.10".parse::<Decimal>().unwrap())).unwrap();
+        assert_eq!(actual, json!({ "value": "0.10", "words": "zero point one zero" }));
+
+        let actual = serde_json::to_value(NumbersToWords::new("007".parse::<Digits>().unwrap())).unwrap();
+        assert_eq!(actual, json!({ "value": "007", "words": "zero zero seven" }));
+
+        let actual = serde_json::to_value(NumbersToWords::new(Year(1984))).unwrap();
+        assert_eq!(actual, json!({ "value": 1984, "words": "nineteen eighty four" }));
+    }
+
+    #[test]
+    fn serializes_rendered_amounts() {
+        let actual = serde_json::to_value(NumbersToWords::new(Money::new(123456, Currency::GBP))).unwrap();
+        assert_eq!(actual, json!({
+            "value": "1234.56 GBP",
+            "words": "one thousand two hundred and thirty four pounds and fifty six pence",
+        }));
+
+        let actual = serde_json::to_value(NumbersToWords::new(Approximate::new(1_234_567_u32))).unwrap();
+        assert_eq!(actual, json!({ "value": 1_234_567, "words": "about one point two million" }));
+
+        let actual = serde_json::to_value(NumbersToWords::new(Counted::new(21_u8, Noun::new("file", "files")))).unwrap();
+        assert_eq!(actual, json!({ "value": 21, "words": "twenty one files" }));
+
+        let actual = serde_json::to_value(NumbersToWords::new(Time::new(9, 5).unwrap())).unwrap();
+        assert_eq!(actual["value"], json!("09:05"));
+
+        let actual = serde_json::to_value(NumbersToWords::new(Date::new(21, 3).unwrap())).unwrap();
+        assert_eq!(actual, json!({ "value": "--03-21", "words": "the twenty first of March" }));
+
+        let actual = serde_json::to_value(NumbersToWords::new(Date::new(21, 3).unwrap().year(1984).unwrap())).unwrap();
+        assert_eq!(actual["value"], json!("1984-03-21"));
+    }
+
+    #[test]
+    fn serializes_input_errors() {
+        let actual = serde_json::to_value(NumbersToWords::<i64>::validate("12x", 0, 100).err().unwrap()).unwrap();
+        assert_eq!(actual, json!({
+            "kind": "parse_error",
+            "input": "12x",
+            "position": 2,
+            "message": "12x cannot be parsed, failed at position 2",
+        }));
+
+        let actual = serde_json::to_value(NumbersToWords::<i64>::validate("150", 0, 100).err().unwrap()).unwrap();
+        assert_eq!(actual, json!({
+            "kind": "validation_error",
+            "input": "150",
+            "value": 150,
+            "rule": { "kind": "range", "low": 0, "high": 100 },
+            "message": "150 is not a valid number, it must be between 0 and 100",
+        }));
+
+        let rules = NumbersToWords::<i64>::rules().multiple_of(5).exclude(10).custom("even", |n| n % 2 == 0);
+        let rule = |input| serde_json::to_value(rules.validate(input).err().unwrap()).unwrap()["rule"].clone();
+
+        assert_eq!(rule("7"), json!({ "kind": "multiple_of", "step": 5 }));
+        assert_eq!(rule("10"), json!({ "kind": "exclude", "value": 10 }));
+        assert_eq!(rule("15"), json!({ "kind": "custom", "name": "even" }));
+    }
+
+    #[test]
+    fn deserializes_with_validation() {
+        let words = |value: serde_json::Value| amount(value).map(|words| format!("{words}")).map_err(|err| err.to_string());
+
+        assert_eq!(words(json!(42)), Ok("forty two".to_string()));
+        assert_eq!(words(json!("999")), Ok("nine hundred and ninety nine".to_string()));
+        assert_eq!(words(json!(1_001)), Err("1001 is not a valid number, it must be between 0 and 1000".to_string()));
+        assert_eq!(words(json!(13)), Err("13 is not a valid number, it must not be 13".to_string()));
+        assert_eq!(words(json!("4o")), Err("4o cannot be parsed, failed at position 1".to_string()));
+        assert_eq!(words(json!(2.5)), Err("2.5 cannot be parsed, failed at position 1".to_string()));
+        assert!(words(json!(true)).is_err());
+    }
+
+    #[test]
+    fn deserializes_fields() {
+        #[derive(Deserialize)]
+        struct Invoice {
+            #[serde(deserialize_with = "validated::<NumbersToWords<u32>, _>")]
+            lines: NumbersToWords<u32>,
+            #[serde(deserialize_with = "amount")]
+            total: NumbersToWords<i64>,
+        }
+
+        let invoice: Invoice = serde_json::from_value(json!({ "lines": "3", "total": 120 })).unwrap();
+        assert_eq!(format!("{}", invoice.lines), "three");
+        assert_eq!(format!("{}", invoice.total), "one hundred and twenty");
+
+        let actual = serde_json::from_value::<Invoice>(json!({ "lines": -3, "total": 120 })).err().unwrap();
+        assert_eq!(actual.to_string(), "-3 cannot be parsed, failed at position 0");
+
+        let actual = serde_json::from_value::<Invoice>(json!({ "lines": 3, "total": 13 })).err().unwrap();
+        assert_eq!(actual.to_string(), "13 is not a valid number, it must not be 13");
+    }
+
+    #[test]
+    fn deserializes_decimals_exactly() {
+        let actual = NumbersToWords::<Decimal>::rules().deserialize(json!("3.10")).unwrap();
+        assert_eq!(format!("{actual}"), "three point one zero");
+    }
+}
@@ -0,0 +1,320 @@
+use alloc::{format, string::String, vec::Vec};
+use core::ops::Range;
+
+use crate::{
+    currency::{Currency, Money},
+    decimal::Decimal,
+    digit_string::DigitString,
+    numbers_to_words::NumbersToWords,
+    ordinal::Ordinal,
+    render_options::RenderOptions,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Category {
+    // "42", "-7" or "1,234"
+    Integer,
+    // "3.14"
+    Decimal,
+    // "£5" or "$1,299.99"
+    Currency,
+    // "21st"
+    Ordinal,
+    // "50%"
+    Percentage,
+}
+
+impl Category {
+    pub const ALL: [Category; 5] = [
+        Category::Integer,
+        Category::Decimal,
+        Category::Currency,
+        Category::Ordinal,
+        Category::Percentage,
+    ];
+}
+
+// Symbols written before an amount
+const SYMBOLS: [(char, Currency); 4] = [
+    ('£', Currency::GBP),
+    ('$', Currency::USD),
+    ('€', Currency::EUR),
+    ('¥', Currency::JPY),
+];
+
+// A numeral found in the text and the words that replace it
+#[derive(Debug, Clone, PartialEq)]
+pub struct Expansion {
+    // Byte range of the numeral in the original text
+    pub span: Range<usize>,
+    pub category: Category,
+    pub words: String,
+}
+
+// Finds the numerals in free text and writes them out as words
+pub struct TextToWords<'a> {
+    text: &'a str,
+    categories: &'a [Category],
+    options: RenderOptions,
+}
+
+fn digits_end(bytes: &[u8], from: usize) -> usize {
+    from + bytes[from..].iter().take_while(|byte| byte.is_ascii_digit()).count()
+}
+
+impl<'a> TextToWords<'a> {
+    pub fn new(text: &'a str) -> Self {
+        TextToWords {
+            text,
+            categories: &Category::ALL,
+            options: RenderOptions::default(),
+        }
+    }
+
+    // Numerals of any other category are left as they are written
+    pub fn categories(mut self, categories: &'a [Category]) -> Self {
+        self.categories = categories;
+        self
+    }
+
+    pub fn options(mut self, options: RenderOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    // The numerals in the order they appear, with their spans in the original text
+    pub fn expansions(&self) -> Vec<Expansion> {
+        let mut expansions = Vec::new();
+        let mut position = 0;
+        while let Some(c) = self.text[position..].chars().next() {
+            match self.numeral(position) {
+                Some(expansion) => {
+                    position = expansion.span.end;
+                    if self.categories.contains(&expansion.category) {
+                        expansions.push(expansion);
+                    }
+                }
+                None => position += c.len_utf8(),
+            }
+        }
+
+        expansions
+    }
+
+    // The text with every numeral replaced by its words
+    pub fn expand(&self) -> String {
+        let mut expanded = String::with_capacity(self.text.len());
+        let mut copied = 0;
+        for expansion in self.expansions() {
+            expanded.push_str(&self.text[copied..expansion.span.start]);
+            expanded.push_str(&expansion.words);
+            copied = expansion.span.end;
+        }
+
+        expanded.push_str(&self.text[copied..]);
+        expanded
+    }
+
+    // The numeral starting at this byte, written as "-£1,234.50", "21st" or "50%"
+    fn numeral(&self, start: usize) -> Option<Expansion> {
+        let (text, bytes) = (self.text, self.text.as_bytes());
+
+        // Digits inside words, codes and version numbers, as in "mp3", "A4" or "1.2.3", are not numerals
+        let joined = |c: char| c.is_alphanumeric() || c == '_' || c == '.';
+        if text[..start].chars().next_back().is_some_and(joined) {
+            return None;
+        }
+
+        let mut position = start;
+        let negative = bytes[position] == b'-';
+        if negative {
+            position += 1;
+        }
+
+        let currency = SYMBOLS
+            .iter()
+            .find(|(symbol, _)| text[position..].starts_with(*symbol))
+            .map(|(symbol, currency)| {
+                position += symbol.len_utf8();
+                *currency
+            });
+
+        let integer_start = position;
+        position = digits_end(bytes, position);
+        if position == integer_start {
+            return None;
+        }
+
+        let mut number = String::from(if negative { "-" } else { "" });
+        number.push_str(&text[integer_start..position]);
+
+        // Commas only group threes after a short leading run, as in "1,234,567"
+        if position - integer_start <= 3 {
+            while bytes.get(position) == Some(&b',') && digits_end(bytes, position + 1) == position + 4 {
+                number.push_str(&text[position + 1..position + 4]);
+                position += 4;
+            }
+        }
+
+        let fraction = bytes.get(position) == Some(&b'.') && bytes.get(position + 1).is_some_and(u8::is_ascii_digit);
+        if fraction {
+            let end = digits_end(bytes, position + 1);
+            number.push_str(&text[position..end]);
+            position = end;
+        }
+
+        let letters = bytes[position..].iter().take_while(|byte| byte.is_ascii_alphabetic()).count();
+        let ordinal = currency.is_none() && !negative && !fraction && letters == 2;
+        let category = match bytes.get(position) {
+            _ if currency.is_some() => Category::Currency,
+            Some(b'%') => Category::Percentage,
+            _ if ordinal && format!("{}{}", number, &text[position..position + 2]).parse::<Ordinal<DigitString>>().is_ok() => {
+                Category::Ordinal
+            }
+            _ if fraction => Category::Decimal,
+            _ => Category::Integer,
+        };
+
+        position += match category {
+            Category::Percentage => 1,
+            Category::Ordinal => 2,
+            _ => 0,
+        };
+
+        // A full stop ends a sentence, but one followed by a digit continues the numeral
+        let rest = &text[position..];
+        let continues = rest.starts_with(|c: char| joined(c) && c != '.')
+            || (rest.starts_with('.') && rest[1..].starts_with(|c: char| c.is_ascii_digit()));
+        if continues {
+            return None;
+        }
+
+        Some(Expansion {
+            span: start..position,
+            category,
+            words: self.words(category, &number, currency)?,
+        })
+    }
+
+    fn words(&self, category: Category, number: &str, currency: Option<Currency>) -> Option<String> {
+        let decimal = || number.parse::<Decimal>().ok();
+        let words = match category {
+            Category::Integer => format!("{}", NumbersToWords::new(number.parse::<DigitString>().ok()?).options(self.options)),
+            Category::Decimal => format!("{}", NumbersToWords::new(decimal()?).options(self.options)),
+            Category::Percentage => format!("{} percent", NumbersToWords::new(decimal()?).options(self.options)),
+            Category::Ordinal => {
+                let digits = number.parse::<DigitString>().ok()?;
+                format!("{}", NumbersToWords::new(digits).options(self.options).ordinal())
+            }
+            Category::Currency => {
+                let (amount, currency) = (decimal()?, currency?);
+                match Money::from_decimal(&amount, currency) {
+                    Some(money) => format!("{}", NumbersToWords::new(money).options(self.options)),
+                    // Amounts finer than the currency's smallest coin, as in "$0.001", are read as decimals
+                    None => format!("{} {}", NumbersToWords::new(amount).options(self.options), currency.major.plural),
+                }
+            }
+        };
+
+        Some(words)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{string::{String, ToString}, vec};
+
+    use super::{Category, Expansion, TextToWords};
+    use crate::render_options::RenderOptions;
+
+    fn expand(text: &str) -> String {
+        TextToWords::new(text).expand()
+    }
+
+    #[test]
+    fn expands_integers() {
+        assert_eq!(expand("I have 3 cats"), "I have three cats");
+        assert_eq!(expand("42"), "forty two");
+        assert_eq!(expand("It was -7 outside."), "It was minus seven outside.");
+        assert_eq!(expand("1,234,567 people"), "one million two hundred and thirty four thousand five hundred and sixty seven people");
+        assert_eq!(expand("pages 10-12"), "pages ten-twelve");
+        assert_eq!(expand("1, 2 and 3"), "one, two and three");
+        assert_eq!(expand("007"), "seven");
+    }
+
+    #[test]
+    fn expands_decimals() {
+        assert_eq!(expand("pi is 3.14."), "pi is three point one four.");
+        assert_eq!(expand("-0.5"), "minus zero point five");
+        assert_eq!(expand("1,000.25"), "one thousand point two five");
+    }
+
+    #[test]
+    fn expands_currency_amounts() {
+        assert_eq!(expand("It costs £5."), "It costs five pounds.");
+        assert_eq!(expand("$1,299.99"), "one thousand two hundred and ninety nine dollars and ninety nine cents");
+        assert_eq!(expand("€0.50 each"), "fifty cents each");
+        assert_eq!(expand("¥500"), "five hundred yen");
+        assert_eq!(expand("-$3"), "minus three dollars");
+        assert_eq!(expand("$0.001"), "zero point zero zero one dollars");
+    }
+
+    #[test]
+    fn expands_ordinals() {
+        assert_eq!(expand("the 21st of March"), "the twenty first of March");
+        assert_eq!(expand("1st, 2nd, 3rd and 4th"), "first, second, third and fourth");
+        assert_eq!(expand("the 1,000th visitor"), "the one thousandth visitor");
+        assert_eq!(expand("the 21th"), "the 21th");
+    }
+
+    #[test]
+    fn expands_percentages() {
+        assert_eq!(expand("50% off"), "fifty percent off");
+        assert_eq!(expand("up 2.5%."), "up two point five percent.");
+        assert_eq!(expand("-3%"), "minus three percent");
+    }
+
+    #[test]
+    fn leaves_codes_and_versions() {
+        assert_eq!(expand("mp3 and A4"), "mp3 and A4");
+        assert_eq!(expand("3D printing"), "3D printing");
+        assert_eq!(expand("version 1.2.3"), "version 1.2.3");
+        assert_eq!(expand("192.168.0.1"), "192.168.0.1");
+        assert_eq!(expand("snake_case_2"), "snake_case_2");
+        assert_eq!(expand("no numbers here"), "no numbers here");
+        assert_eq!(expand(""), "");
+    }
+
+    #[test]
+    fn keeps_original_spans() {
+        let expansions = TextToWords::new("café £12 on the 3rd, 5%").expansions();
+
+        assert_eq!(
+            expansions,
+            vec![
+                Expansion { span: 6..10, category: Category::Currency, words: "twelve pounds".to_string() },
+                Expansion { span: 18..21, category: Category::Ordinal, words: "third".to_string() },
+                Expansion { span: 23..25, category: Category::Percentage, words: "five percent".to_string() },
+            ]
+        );
+    }
+
+    #[test]
+    fn expands_chosen_categories() {
+        let text = "£5 for the 2nd item, 10% off 3.5 kg of 7 apples";
+
+        let actual = TextToWords::new(text).categories(&[Category::Ordinal, Category::Integer]).expand();
+        assert_eq!(actual, "£5 for the second item, 10% off 3.5 kg of seven apples");
+
+        let actual = TextToWords::new(text).categories(&[Category::Currency, Category::Percentage]).expand();
+        assert_eq!(actual, "five pounds for the 2nd item, ten percent off 3.5 kg of 7 apples");
+
+        assert_eq!(TextToWords::new(text).categories(&[]).expand(), text);
+    }
+
+    #[test]
+    fn expands_with_options() {
+        let actual = TextToWords::new("the 21st, 1,042 and 99%").options(RenderOptions::AMERICAN).expand();
+        assert_eq!(actual, "the twenty-first, one thousand forty-two and ninety-nine percent");
+    }
+}
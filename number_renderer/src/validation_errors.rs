@@ -1,25 +1,208 @@
-use std::{fmt::{Display, Debug}, error::Error};
+use alloc::string::String;
+use core::{
+    fmt::Display,
+    num::{IntErrorKind, ParseFloatError, ParseIntError},
+};
+
+use crate::validator::Rule;
 
 #[derive(Debug)]
 pub enum InputError<N: Display> {
-    ParseError(String),
-    ValidationError(N)
+    ParseError { input: String, position: usize },
+    // The input parsed, but the value broke one of the rules
+    ValidationError { input: String, value: N, rule: Rule<N> },
 }
 
-impl<N> Error for InputError<N>
+#[cfg(feature = "std")]
+impl<N> std::error::Error for InputError<N>
 where 
-    N: Display + Debug 
+    N: Display + core::fmt::Debug 
 {}
 
 impl<N: Display> Display for InputError<N> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
-            InputError::<N>::ParseError(e) => {
-                write!(f, "{} cannot be parsed", e)
+            InputError::<N>::ParseError { input, position } => {
+                write!(f, "{} cannot be parsed, failed at position {}", input, position)
             },
-            InputError::<N>::ValidationError(e) => {
-                write!(f, "{} is not a valid number", e)
+            InputError::<N>::ValidationError { value, rule, .. } => {
+                write!(f, "{} is not a valid number, it {}", value, rule)
             },
         }
     }
 }
+
+#[derive(Debug, PartialEq)]
+pub struct ParseDigitsError {
+    pub position: usize,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseDigitsError {}
+
+impl Display for ParseDigitsError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "invalid digit at position {}", self.position)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ParseWordsError {
+    // A word that is not a number word, or cannot follow the word before it
+    UnexpectedToken { token: String, position: usize },
+    // The words stop part way through a number, as with "one hundred and"
+    UnexpectedEnd { position: usize },
+    // The words name a number that does not fit in an i64
+    OutOfRange { position: usize },
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseWordsError {}
+
+impl Display for ParseWordsError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParseWordsError::UnexpectedToken { token, position } => {
+                write!(f, "unexpected word \"{}\" at position {}", token, position)
+            }
+            ParseWordsError::UnexpectedEnd { position } => {
+                write!(f, "unexpected end of words at position {}", position)
+            }
+            ParseWordsError::OutOfRange { position } => {
+                write!(f, "number at position {} is out of range", position)
+            }
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ParseRomanError {
+    // A character that is not a numeral, or a vinculum with nothing under it
+    InvalidNumeral { position: usize },
+    // Numerals that add up but are not how the number is written, "IIII" rather than "IV"
+    NonCanonical { position: usize },
+    // Zero, or past what even a vinculum can write
+    OutOfRange { position: usize },
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseRomanError {}
+
+impl Display for ParseRomanError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParseRomanError::InvalidNumeral { position } => {
+                write!(f, "invalid numeral at position {}", position)
+            }
+            ParseRomanError::NonCanonical { position } => {
+                write!(f, "numerals are not written the standard way from position {}", position)
+            }
+            ParseRomanError::OutOfRange { position } => {
+                write!(f, "numerals at position {} are out of range", position)
+            }
+        }
+    }
+}
+
+// Where in the input a parse error happened, as a byte offset
+pub trait ParsePosition {
+    fn position(&self, input: &str) -> usize;
+}
+
+// Length of the longest prefix that reads as a signed run of digits
+fn scan_integer(input: &str) -> usize {
+    let unsigned = input.strip_prefix(['+', '-']).unwrap_or(input);
+    let sign = input.len() - unsigned.len();
+
+    sign + unsigned
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(unsigned.len())
+}
+
+impl ParsePosition for ParseIntError {
+    fn position(&self, input: &str) -> usize {
+        match self.kind() {
+            // A well formed integer can only be invalid for its sign, as with "-5" for a u64
+            IntErrorKind::InvalidDigit => match scan_integer(input) {
+                end if end == input.len() => 0,
+                end => end,
+            },
+            _ => input.len(),
+        }
+    }
+}
+
+impl ParsePosition for ParseFloatError {
+    fn position(&self, input: &str) -> usize {
+        let mut end = scan_integer(input);
+        if input[end..].starts_with('.') {
+            end += 1 + input[end + 1..]
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(input.len() - end - 1);
+        }
+
+        if input[end..].starts_with(['e', 'E']) {
+            let exponent = scan_integer(&input[end + 1..]);
+            if input[end + 1..end + 1 + exponent].ends_with(|c: char| c.is_ascii_digit()) {
+                end += 1 + exponent;
+            }
+        }
+
+        end
+    }
+}
+
+impl ParsePosition for ParseDigitsError {
+    fn position(&self, _: &str) -> usize {
+        self.position
+    }
+}
+
+impl ParsePosition for ParseWordsError {
+    fn position(&self, _: &str) -> usize {
+        match self {
+            ParseWordsError::UnexpectedToken { position, .. }
+            | ParseWordsError::UnexpectedEnd { position }
+            | ParseWordsError::OutOfRange { position } => *position,
+        }
+    }
+}
+
+impl ParsePosition for ParseRomanError {
+    fn position(&self, _: &str) -> usize {
+        match self {
+            ParseRomanError::InvalidNumeral { position }
+            | ParseRomanError::NonCanonical { position }
+            | ParseRomanError::OutOfRange { position } => *position,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ParsePosition;
+
+    #[test]
+    fn positions_integer_errors() {
+        let position = |input: &str| input.parse::<i64>().unwrap_err().position(input);
+
+        assert_eq!(position(""), 0);
+        assert_eq!(position("abc"), 0);
+        assert_eq!(position("12a4"), 2);
+        assert_eq!(position("-1.5"), 2);
+        assert_eq!(position("--1"), 1);
+        assert_eq!(position("99999999999999999999"), 20);
+    }
+
+    #[test]
+    fn positions_float_errors() {
+        let position = |input: &str| input.parse::<f64>().unwrap_err().position(input);
+
+        assert_eq!(position(""), 0);
+        assert_eq!(position("x1"), 0);
+        assert_eq!(position("1.2.3"), 3);
+        assert_eq!(position("-3.14x"), 5);
+        assert_eq!(position("1e"), 1);
+        assert_eq!(position("1e+5z"), 4);
+    }
+}
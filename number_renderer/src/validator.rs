@@ -1,7 +1,296 @@
-pub trait Validator {
+use alloc::{boxed::Box, vec::Vec};
+use core::{fmt::Display, marker::PhantomData};
+
+use crate::{
+    lenient::{NumberFormat, ParseMode},
+    numbers_to_words::NumbersToWords,
+};
+
+// A rule a parsed value has to follow, reported back when the value breaks it
+#[derive(Debug, Clone, PartialEq)]
+pub enum Rule<N> {
+    // Inclusive at both ends
+    Range { low: N, high: N },
+    Exclude(N),
+    MultipleOf(N),
+    // A caller supplied check, known by its name
+    Custom(&'static str),
+}
+
+impl<N: Display> Display for Rule<N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Rule::Range { low, high } => write!(f, "must be between {} and {}", low, high),
+            Rule::Exclude(value) => write!(f, "must not be {}", value),
+            Rule::MultipleOf(step) => write!(f, "must be a multiple of {}", step),
+            Rule::Custom(name) => write!(f, "must be {}", name),
+        }
+    }
+}
+
+// The remainder after division, None where it would overflow, as for i64::MIN % -1, or
+// where the divisor is zero
+pub trait CheckedRem: Sized {
+    fn checked_rem(self, rhs: Self) -> Option<Self>;
+}
+
+macro_rules! checked_rem_integer {
+    ($($t:ty),*) => {
+        $(
+            impl CheckedRem for $t {
+                fn checked_rem(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_rem(self, rhs)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! checked_rem_float {
+    ($($t:ty),*) => {
+        $(
+            impl CheckedRem for $t {
+                fn checked_rem(self, rhs: Self) -> Option<Self> {
+                    Some(self % rhs)
+                }
+            }
+        )*
+    };
+}
+
+checked_rem_integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+checked_rem_float!(f32, f64);
+
+// A rule paired with the check that enforces it
+type Check<N> = (Rule<N>, Box<dyn Fn(&N) -> bool>);
+
+// Rules checked in the order they were added, built up from Validator::rules
+pub struct Rules<V: Validator> {
+    checks: Vec<Check<V::Bounds>>,
+    pub(crate) mode: ParseMode,
+    // Only read by Roman numerals, set by lenient_numerals
+    pub(crate) non_canonical: bool,
+    validator: PhantomData<fn() -> V>,
+}
+
+impl<V: Validator> Rules<V> {
+    pub fn new() -> Self {
+        Rules {
+            checks: Vec::new(),
+            mode: ParseMode::Strict,
+            non_canonical: false,
+            validator: PhantomData,
+        }
+    }
+
+    fn rule(mut self, rule: Rule<V::Bounds>, check: impl Fn(&V::Bounds) -> bool + 'static) -> Self {
+        self.checks.push((rule, Box::new(check)));
+        self
+    }
+
+    pub fn range(self, low: V::Bounds, high: V::Bounds) -> Self
+    where
+        V::Bounds: PartialOrd + Clone + 'static,
+    {
+        let rule = Rule::Range {
+            low: low.clone(),
+            high: high.clone(),
+        };
+
+        // Written so that values without an ordering, like NaN, are rejected
+        self.rule(rule, move |value| *value >= low && *value <= high)
+    }
+
+    pub fn exclude(self, excluded: V::Bounds) -> Self
+    where
+        V::Bounds: PartialEq + Clone + 'static,
+    {
+        self.rule(Rule::Exclude(excluded.clone()), move |value| *value != excluded)
+    }
+
+    // Only zero is a multiple of zero. The one remainder that overflows, MIN % -1, is zero
+    pub fn multiple_of(self, step: V::Bounds) -> Self
+    where
+        V::Bounds: CheckedRem + Default + PartialEq + Copy + 'static,
+    {
+        let zero = V::Bounds::default();
+        self.rule(Rule::MultipleOf(step), move |value| {
+            if step == zero {
+                return *value == zero;
+            }
+
+            value.checked_rem(step).is_none_or(|remainder| remainder == zero)
+        })
+    }
+
+    pub fn custom(self, name: &'static str, check: impl Fn(&V::Bounds) -> bool + 'static) -> Self {
+        self.rule(Rule::Custom(name), check)
+    }
+
+    // The first rule the value breaks, if any
+    pub fn broken(&self, value: &V::Bounds) -> Option<&Rule<V::Bounds>> {
+        self.checks
+            .iter()
+            .find(|(_, check)| !check(value))
+            .map(|(rule, _)| rule)
+    }
+
+    pub fn validate<S: AsRef<str>>(&self, input: S) -> Result<V::Output, V::Error> {
+        V::check(input, self)
+    }
+}
+
+impl<N> Rules<NumbersToWords<N>>
+where
+    NumbersToWords<N>: Validator,
+{
+    // Accepts "1,000", "1.2k", "3 million" or "1e6" as well as what str::parse accepts
+    pub fn lenient(mut self, format: NumberFormat) -> Self {
+        self.mode = ParseMode::Lenient(format);
+        self
+    }
+}
+
+impl<V: Validator> Default for Rules<V> {
+    fn default() -> Self {
+        Rules::new()
+    }
+}
+
+pub trait Validator: Sized {
     type Bounds;
     type Output;
     type Error;
 
-    fn validate(input: String, low: Self::Bounds, high: Self::Bounds) -> Result<Self::Output, Self::Error>;
-}
\ No newline at end of file
+    // Parses the input and checks it against the rules, stopping at the first one it breaks
+    fn check<S: AsRef<str>>(input: S, rules: &Rules<Self>) -> Result<Self::Output, Self::Error>;
+
+    fn rules() -> Rules<Self> {
+        Rules::new()
+    }
+
+    // Takes the input as a String or a &str, only copying it into the error when it fails
+    fn validate<S: AsRef<str>>(input: S, low: Self::Bounds, high: Self::Bounds) -> Result<Self::Output, Self::Error>
+    where
+        Self::Bounds: PartialOrd + Clone + 'static,
+    {
+        Self::rules().range(low, high).validate(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::format;
+
+    use super::{Rule, Validator};
+    use crate::{
+        decimal::Decimal, lenient::NumberFormat, numbers_to_words::NumbersToWords,
+        validation_errors::InputError,
+    };
+
+    type Words = NumbersToWords<i64>;
+
+    fn broken(result: Result<Words, InputError<i64>>) -> Option<Rule<i64>> {
+        match result {
+            Err(InputError::ValidationError { rule, .. }) => Some(rule),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn validates_ranges() {
+        let rules = Words::rules().range(1, 10).range(-5, 5);
+
+        assert_eq!(format!("{}", rules.validate("4").unwrap()), "four");
+        assert_eq!(broken(rules.validate("0")), Some(Rule::Range { low: 1, high: 10 }));
+        assert_eq!(broken(rules.validate("7")), Some(Rule::Range { low: -5, high: 5 }));
+    }
+
+    #[test]
+    fn validates_exclusions_and_multiples() {
+        let rules = Words::rules().multiple_of(5).exclude(15);
+
+        assert!(rules.validate("-20").is_ok());
+        assert!(rules.validate("0").is_ok());
+        assert_eq!(broken(rules.validate("15")), Some(Rule::Exclude(15)));
+        assert_eq!(broken(rules.validate("12")), Some(Rule::MultipleOf(5)));
+
+        let zero = Words::rules().multiple_of(0);
+        assert!(zero.validate("0").is_ok());
+        assert_eq!(broken(zero.validate("3")), Some(Rule::MultipleOf(0)));
+
+        let minus_one = Words::rules().multiple_of(-1);
+        assert!(minus_one.validate("-9223372036854775808").is_ok());
+        assert!(minus_one.validate("9223372036854775807").is_ok());
+
+        let half = NumbersToWords::<f64>::rules().multiple_of(0.5);
+        assert!(half.validate("2.5").is_ok());
+        assert!(half.validate("2.25").is_err());
+    }
+
+    #[test]
+    fn validates_custom_rules() {
+        let rules = Words::rules().custom("even", |n| n % 2 == 0).range(0, 100);
+
+        assert!(rules.validate("42").is_ok());
+        assert_eq!(broken(rules.validate("41")), Some(Rule::Custom("even")));
+        assert_eq!(broken(rules.validate("102")), Some(Rule::Range { low: 0, high: 100 }));
+    }
+
+    #[test]
+    fn reports_input_value_and_rule() {
+        let actual = Words::rules().exclude(13).validate(" 13".trim());
+        assert!(matches!(
+            &actual,
+            Err(InputError::ValidationError { input, value: 13, rule: Rule::Exclude(13) }) if input == "13"
+        ));
+
+        let actual = actual.err().unwrap();
+        assert_eq!(format!("{actual}"), "13 is not a valid number, it must not be 13");
+
+        let actual = Words::validate("-1", 0, 10).err().unwrap();
+        assert_eq!(format!("{actual}"), "-1 is not a valid number, it must be between 0 and 10");
+
+        let actual = Words::rules().validate("12a").err().unwrap();
+        assert_eq!(format!("{actual}"), "12a cannot be parsed, failed at position 2");
+    }
+
+    #[test]
+    fn validates_lenient_input() {
+        let rules = Words::rules().lenient(NumberFormat::ENGLISH).range(0, 10_000_000);
+
+        assert_eq!(format!("{}", rules.validate("1,000,000").unwrap()), "one million");
+        assert_eq!(format!("{}", rules.validate("1_000").unwrap()), "one thousand");
+        assert_eq!(format!("{}", rules.validate(" 42 ").unwrap()), "forty two");
+        assert_eq!(format!("{}", rules.validate("1e6").unwrap()), "one million");
+        assert_eq!(format!("{}", rules.validate("1.2k").unwrap()), "one thousand two hundred");
+        assert_eq!(format!("{}", rules.validate("3 million").unwrap()), "three million");
+        assert_eq!(broken(rules.validate("2e7")), Some(Rule::Range { low: 0, high: 10_000_000 }));
+
+        let german = NumbersToWords::<Decimal>::rules().lenient(NumberFormat::GERMAN);
+        assert_eq!(format!("{}", german.validate("1.234,5").unwrap()), "one thousand two hundred and thirty four point five");
+    }
+
+    #[test]
+    fn reports_lenient_positions_in_the_input() {
+        let rules = Words::rules().lenient(NumberFormat::ENGLISH);
+
+        let actual = rules.validate("1,000.5").err().unwrap();
+        assert!(matches!(actual, InputError::ParseError { position: 5, .. }));
+
+        let actual = rules.validate("12x").err().unwrap();
+        assert!(matches!(actual, InputError::ParseError { position: 2, .. }));
+
+        let actual = NumbersToWords::<u8>::rules().lenient(NumberFormat::ENGLISH).validate("1k").err().unwrap();
+        assert!(matches!(actual, InputError::ParseError { .. }));
+    }
+
+    #[test]
+    fn validates_strictly_by_default() {
+        let rules = Words::rules();
+
+        ["1,000", "1_000", " 42", "1e6", "1.2k", "3 million"]
+            .iter()
+            .for_each(|input| assert!(matches!(rules.validate(input), Err(InputError::ParseError { .. }))));
+    }
+}
@@ -0,0 +1,352 @@
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+use crate::{render_options::RenderOptions, validation_errors::ParseWordsError};
+
+const UNITS: [&str; 10] = ["zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine"];
+
+const TEENS: [&str; 10] = [
+    "ten", "eleven", "twelve", "thirteen", "fourteen", "fifteen", "sixteen", "seventeen", "eighteen", "nineteen",
+];
+
+const TENS: [&str; 10] = ["", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety"];
+
+// Every scale that fits in an i64, in the short and long scale
+const SHORT_SCALES: [(&str, u128); 6] = [
+    ("thousand", 1_000),
+    ("million", 1_000_000),
+    ("billion", 1_000_000_000),
+    ("trillion", 1_000_000_000_000),
+    ("quadrillion", 1_000_000_000_000_000),
+    ("quintillion", 1_000_000_000_000_000_000),
+];
+
+const LONG_SCALES: [(&str, u128); 6] = [
+    ("thousand", 1_000),
+    ("million", 1_000_000),
+    ("milliard", 1_000_000_000),
+    ("billion", 1_000_000_000_000),
+    ("billiard", 1_000_000_000_000_000),
+    ("trillion", 1_000_000_000_000_000_000),
+];
+
+const NEGATIVE_WORDS: [&str; 2] = ["minus", "negative"];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Word {
+    Negative,
+    Zero,
+    Unit(u128),
+    Teen(u128),
+    Tens(u128),
+    Hundred,
+    And,
+    Scale(u128),
+}
+
+// What the last word was, which decides the words that may follow it
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum State {
+    Start,
+    Negative,
+    Zero,
+    Unit,
+    Teen,
+    Tens,
+    Hundred,
+    And,
+    Scale,
+}
+
+// Reads numbers written the way NumbersToWords writes them back into an i64
+pub struct WordsToNumbers<'a> {
+    words: &'a str,
+    options: RenderOptions,
+}
+
+impl<'a> WordsToNumbers<'a> {
+    pub fn new(words: &'a str) -> Self {
+        WordsToNumbers {
+            words,
+            options: RenderOptions::default(),
+        }
+    }
+
+    // Only the scale matters when reading, "and" and hyphens are always accepted
+    pub fn options(mut self, options: RenderOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    fn scales(&self) -> &'static [(&'static str, u128)] {
+        match self.options.long_scale {
+            true => &LONG_SCALES,
+            false => &SHORT_SCALES,
+        }
+    }
+
+    fn word(&self, token: &str) -> Option<Word> {
+        if NEGATIVE_WORDS.contains(&token) {
+            return Some(Word::Negative);
+        }
+
+        let position = |table: &[&str]| table.iter().position(|word| *word == token).map(|n| n as u128);
+        match token {
+            "zero" => Some(Word::Zero),
+            "hundred" => Some(Word::Hundred),
+            "and" => Some(Word::And),
+            _ => position(&UNITS)
+                .map(Word::Unit)
+                .or_else(|| position(&TEENS).map(|n| Word::Teen(n + 10)))
+                .or_else(|| position(&TENS).map(|n| Word::Tens(n * 10)))
+                .or_else(|| {
+                    self.scales()
+                        .iter()
+                        .find(|(name, _)| *name == token)
+                        .map(|(_, scale)| Word::Scale(*scale))
+                }),
+        }
+    }
+
+    // The cardinal word an ordinal is made from, "first" as "one" or "twentieth" as "twenty"
+    fn cardinal(&self, token: &str) -> Option<Word> {
+        let cardinal = match token {
+            "first" => "one".to_string(),
+            "second" => "two".to_string(),
+            "third" => "three".to_string(),
+            "fifth" => "five".to_string(),
+            "eighth" => "eight".to_string(),
+            "ninth" => "nine".to_string(),
+            "twelfth" => "twelve".to_string(),
+            tens if tens.ends_with("ieth") => format!("{}y", &tens[..tens.len() - 4]),
+            word => word.strip_suffix("th")?.to_string(),
+        };
+
+        self.word(&cardinal).filter(|word| !matches!(word, Word::Negative | Word::And))
+    }
+
+    pub fn parse(&self) -> Result<i64, ParseWordsError> {
+        let tokens = tokens(self.words);
+        let unexpected = |(position, token): &(usize, String)| ParseWordsError::UnexpectedToken {
+            token: token.clone(),
+            position: *position,
+        };
+
+        let (mut total, mut group) = (0_u128, 0_u128);
+        let mut last_scale = u128::MAX;
+        let mut negative = false;
+        let mut state = State::Start;
+
+        for (index, token) in tokens.iter().enumerate() {
+            // Only the last word of an ordinal changes form
+            let word = match self.word(&token.1) {
+                Some(word) => word,
+                None if index == tokens.len() - 1 => self.cardinal(&token.1).ok_or_else(|| unexpected(token))?,
+                None => return Err(unexpected(token)),
+            };
+
+            state = match (state, word) {
+                (State::Start, Word::Negative) => {
+                    negative = true;
+                    State::Negative
+                }
+                (State::Start | State::Negative, Word::Zero) => State::Zero,
+                (State::Start | State::Negative | State::Hundred | State::And | State::Scale, Word::Unit(n)) => {
+                    group += n;
+                    State::Unit
+                }
+                (State::Tens, Word::Unit(n)) => {
+                    group += n;
+                    State::Unit
+                }
+                (State::Start | State::Negative | State::Hundred | State::And | State::Scale, Word::Teen(n)) => {
+                    group += n;
+                    State::Teen
+                }
+                (State::Start | State::Negative | State::Hundred | State::And | State::Scale, Word::Tens(n)) => {
+                    group += n;
+                    State::Tens
+                }
+                (State::Unit, Word::Hundred) if group < 10 => {
+                    group *= 100;
+                    State::Hundred
+                }
+                (State::Hundred | State::Scale, Word::And) => State::And,
+                (State::Unit | State::Teen | State::Tens | State::Hundred, Word::Scale(scale)) if scale < last_scale => {
+                    total += group * scale;
+                    group = 0;
+                    last_scale = scale;
+                    State::Scale
+                }
+                _ => return Err(unexpected(token)),
+            };
+        }
+
+        let end = self.words.trim_end().len();
+        if matches!(state, State::Start | State::Negative | State::And) {
+            return Err(ParseWordsError::UnexpectedEnd { position: end });
+        }
+
+        let magnitude = total + group;
+        let start = tokens.first().map(|(position, _)| *position).unwrap_or_default();
+        let out_of_range = ParseWordsError::OutOfRange { position: start };
+        match negative {
+            true => 0_i128
+                .checked_sub_unsigned(magnitude)
+                .and_then(|value| i64::try_from(value).ok())
+                .ok_or(out_of_range),
+            false => i64::try_from(magnitude).map_err(|_| out_of_range),
+        }
+    }
+}
+
+// Lowercased words with their byte positions, split on whitespace and hyphens
+fn tokens(words: &str) -> Vec<(usize, String)> {
+    let mut tokens = vec![];
+    let mut start = None;
+    for (position, c) in words.char_indices().chain([(words.len(), ' ')]) {
+        match (start, c.is_whitespace() || c == '-') {
+            (None, false) => start = Some(position),
+            (Some(from), true) => {
+                tokens.push((from, words[from..position].to_lowercase()));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+
+    tokens
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{format, string::ToString};
+    use proptest::prelude::*;
+
+    use super::WordsToNumbers;
+    use crate::{
+        numbers_to_words::NumbersToWords,
+        render_options::{AndPlacement, RenderOptions},
+        validation_errors::ParseWordsError,
+    };
+
+    fn parse(words: &str) -> Result<i64, ParseWordsError> {
+        WordsToNumbers::new(words).parse()
+    }
+
+    fn unexpected(token: &str, position: usize) -> Result<i64, ParseWordsError> {
+        Err(ParseWordsError::UnexpectedToken {
+            token: token.to_string(),
+            position,
+        })
+    }
+
+    #[test]
+    fn parses_words() {
+        assert_eq!(parse("zero"), Ok(0));
+        assert_eq!(parse("ninety nine"), Ok(99));
+        assert_eq!(parse("three hundred and one"), Ok(301));
+        assert_eq!(parse("six thousand and forty seven"), Ok(6047));
+        assert_eq!(parse("two million and five hundred and ninety"), Ok(2000590));
+        assert_eq!(parse("two million five hundred and ninety"), Ok(2000590));
+        assert_eq!(parse("minus one quadrillion"), Ok(-1_000_000_000_000_000));
+        assert_eq!(parse("negative forty two"), Ok(-42));
+    }
+
+    #[test]
+    fn ignores_case_hyphens_and_spacing() {
+        assert_eq!(parse("Ninety-Nine"), Ok(99));
+        assert_eq!(parse("  TWENTY-one   thousand\tfour hundred twenty-one "), Ok(21_421));
+        assert_eq!(parse("three hundred one"), Ok(301));
+    }
+
+    #[test]
+    fn parses_ordinals() {
+        assert_eq!(parse("first"), Ok(1));
+        assert_eq!(parse("twenty second"), Ok(22));
+        assert_eq!(parse("fortieth"), Ok(40));
+        assert_eq!(parse("one hundred and twenty first"), Ok(121));
+        assert_eq!(parse("one millionth"), Ok(1_000_000));
+        assert_eq!(parse("zeroth"), Ok(0));
+        assert_eq!(parse("first hundred"), unexpected("first", 0));
+    }
+
+    #[test]
+    fn parses_long_scale() {
+        let long_scale = RenderOptions {
+            long_scale: true,
+            ..RenderOptions::default()
+        };
+
+        assert_eq!(WordsToNumbers::new("one milliard").options(long_scale).parse(), Ok(1_000_000_000));
+        assert_eq!(WordsToNumbers::new("two billion").options(long_scale).parse(), Ok(2_000_000_000_000));
+        assert_eq!(parse("one milliard"), unexpected("milliard", 4));
+    }
+
+    #[test]
+    fn parses_type_extremes() {
+        assert_eq!(parse(&format!("{}", NumbersToWords::new(i64::MAX))), Ok(i64::MAX));
+        assert_eq!(parse(&format!("{}", NumbersToWords::new(i64::MIN))), Ok(i64::MIN));
+
+        let too_large = format!("{}", NumbersToWords::new(i64::MAX as u64 + 1));
+        assert_eq!(parse(&too_large), Err(ParseWordsError::OutOfRange { position: 0 }));
+    }
+
+    #[test]
+    fn points_to_unexpected_tokens() {
+        assert_eq!(parse("one hundred and fourty"), unexpected("fourty", 16));
+        assert_eq!(parse("ninety ten"), unexpected("ten", 7));
+        assert_eq!(parse("one two"), unexpected("two", 4));
+        assert_eq!(parse("twenty five hundred"), unexpected("hundred", 12));
+        assert_eq!(parse("one thousand one million"), unexpected("million", 17));
+        assert_eq!(parse("zero one"), unexpected("one", 5));
+        assert_eq!(parse("one minus"), unexpected("minus", 4));
+        assert_eq!(parse("and one"), unexpected("and", 0));
+        assert_eq!(parse("twenty zero"), unexpected("zero", 7));
+
+        assert_eq!(parse(""), Err(ParseWordsError::UnexpectedEnd { position: 0 }));
+        assert_eq!(parse("minus "), Err(ParseWordsError::UnexpectedEnd { position: 5 }));
+        assert_eq!(parse("one hundred and"), Err(ParseWordsError::UnexpectedEnd { position: 15 }));
+    }
+
+    #[test]
+    fn displays_errors() {
+        let actual = parse("one hundred and fourty").err().unwrap();
+        assert_eq!(format!("{actual}"), "unexpected word \"fourty\" at position 16");
+    }
+
+    fn options() -> impl Strategy<Value = RenderOptions> {
+        (any::<bool>(), any::<bool>(), any::<bool>(), any::<bool>()).prop_map(|(and, final_and, hyphenate, long_scale)| {
+            RenderOptions {
+                and,
+                and_placement: if final_and { AndPlacement::Final } else { AndPlacement::Remainder },
+                hyphenate,
+                long_scale,
+            }
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn round_trips(number in any::<i64>(), options in options()) {
+            let words = format!("{}", NumbersToWords::new(number).options(options));
+            prop_assert_eq!(WordsToNumbers::new(&words).options(options).parse(), Ok(number));
+        }
+
+        #[test]
+        fn round_trips_ordinals(number in any::<i64>()) {
+            let words = format!("{}", NumbersToWords::new(number).ordinal());
+            prop_assert_eq!(parse(&words), Ok(number));
+        }
+
+        #[test]
+        fn round_trips_uppercase(number in -1_000_000_i64..1_000_000) {
+            let words = format!("{}", NumbersToWords::new(number)).to_uppercase();
+            prop_assert_eq!(parse(&words), Ok(number));
+        }
+    }
+}
@@ -0,0 +1,255 @@
+use core::fmt::{self, Write as _};
+#[cfg(feature = "std")]
+use std::io;
+
+use crate::{
+    casing::{CaseWriter, Casing},
+    languages::write_words,
+    numbers_to_words::{NumbersToWords, Settings},
+    ordinal::Ordinal,
+};
+
+// Writes English words straight into a writer, without the recursion and
+// intermediate formatting of the Display impl, for rendering in bulk
+pub trait WriteWords {
+    fn write_to<W: fmt::Write>(&self, out: &mut W) -> fmt::Result;
+
+    #[cfg(feature = "std")]
+    fn write_io<W: io::Write>(&self, out: &mut W) -> io::Result<()> {
+        let mut adapter = IoWriter { inner: out, error: None };
+        self.write_to(&mut adapter).map_err(|_| {
+            adapter
+                .error
+                .unwrap_or_else(|| io::Error::other("formatter error"))
+        })
+    }
+
+    // Fills the start of the buffer and returns the words written, failing if they do not fit
+    fn write_to_buffer<'b>(&self, buffer: &'b mut [u8]) -> Result<&'b str, fmt::Error> {
+        let mut writer = BufferWriter { buffer, len: 0 };
+        self.write_to(&mut writer)?;
+
+        let BufferWriter { buffer, len } = writer;
+        core::str::from_utf8(&buffer[..len]).map_err(|_| fmt::Error)
+    }
+}
+
+#[cfg(feature = "std")]
+struct IoWriter<'a, W: io::Write> {
+    inner: &'a mut W,
+    error: Option<io::Error>,
+}
+
+#[cfg(feature = "std")]
+impl<W: io::Write> fmt::Write for IoWriter<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.inner.write_all(s.as_bytes()).map_err(|err| {
+            self.error = Some(err);
+            fmt::Error
+        })
+    }
+}
+
+struct BufferWriter<'b> {
+    buffer: &'b mut [u8],
+    len: usize,
+}
+
+impl fmt::Write for BufferWriter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        if end > self.buffer.len() {
+            return Err(fmt::Error);
+        }
+
+        self.buffer[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+impl<N> NumbersToWords<N> {
+    fn write_sign_and_magnitude<W: fmt::Write>(
+        &self,
+        out: &mut W,
+        negative: bool,
+        magnitude: u128,
+    ) -> fmt::Result {
+        if self.settings.casing == Casing::Lower && self.settings.terminator.is_none() {
+            return self.write_plain(out, negative, magnitude);
+        }
+
+        let mut out = CaseWriter::new(out, self.settings.casing);
+        self.write_plain(&mut out, negative, magnitude)?;
+        if let Some(terminator) = self.settings.terminator {
+            out.write_char(' ')?;
+            out.write_str(terminator)?;
+        }
+
+        out.flush()
+    }
+
+    fn write_plain<W: fmt::Write>(&self, out: &mut W, negative: bool, magnitude: u128) -> fmt::Result {
+        if negative {
+            out.write_str(self.negative())?;
+            out.write_char(' ')?;
+        }
+
+        write_words(out, magnitude, self.settings.options, self.settings.ordinal)
+    }
+}
+
+macro_rules! write_unsigned {
+    ($($t:ty),*) => {
+        $(
+            impl WriteWords for NumbersToWords<$t> {
+                fn write_to<W: fmt::Write>(&self, out: &mut W) -> fmt::Result {
+                    self.write_sign_and_magnitude(out, false, self.number as u128)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! write_signed {
+    ($($t:ty),*) => {
+        $(
+            impl WriteWords for NumbersToWords<$t> {
+                fn write_to<W: fmt::Write>(&self, out: &mut W) -> fmt::Result {
+                    self.write_sign_and_magnitude(out, self.number < 0, self.number.unsigned_abs() as u128)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! write_ordinal {
+    ($($t:ty),*) => {
+        $(
+            impl WriteWords for NumbersToWords<Ordinal<$t>> {
+                fn write_to<W: fmt::Write>(&self, out: &mut W) -> fmt::Result {
+                    let number = NumbersToWords {
+                        number: self.number.0,
+                        language: self.language,
+                        settings: Settings { ordinal: true, ..self.settings },
+                    };
+
+                    number.write_to(out)
+                }
+            }
+        )*
+    };
+}
+
+write_unsigned!(u8, u16, u32, u64, u128, usize);
+write_signed!(i8, i16, i32, i64, i128, isize);
+write_ordinal!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+#[cfg(test)]
+mod tests {
+    use alloc::{format, string::String};
+    use proptest::prelude::*;
+
+    use super::WriteWords;
+    use crate::{
+        casing::Casing,
+        numbers_to_words::NumbersToWords,
+        ordinal::Ordinal,
+        render_options::{AndPlacement, RenderOptions},
+    };
+
+    fn written<N>(words: &NumbersToWords<N>) -> String
+    where
+        NumbersToWords<N>: WriteWords,
+    {
+        let mut out = String::new();
+        words.write_to(&mut out).unwrap();
+        out
+    }
+
+    #[test]
+    fn writes_the_same_words_as_display() {
+        let numbers = [
+            0_i64, 1, 19, 20, 21, 99, 100, 101, 120, 999, 1_000, 1_001, 6047, 21_421, 100_000, 900_047,
+            2000590, 1_002_000_005, 1_000_000_000_000_000, i64::MAX, i64::MIN, -402,
+        ];
+
+        numbers.iter().for_each(|n| {
+            let words = NumbersToWords::new(*n);
+            assert_eq!(written(&words), format!("{words}"));
+
+            let words = NumbersToWords::new(*n).ordinal();
+            assert_eq!(written(&words), format!("{words}"));
+        });
+
+        let words = NumbersToWords::new(u128::MAX);
+        assert_eq!(written(&words), format!("{words}"));
+
+        let words = NumbersToWords::new(Ordinal(255_u8));
+        assert_eq!(written(&words), "two hundred and fifty fifth");
+
+        let words = NumbersToWords::new(-5_i8).negative_word("negative");
+        assert_eq!(written(&words), "negative five");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn writes_to_io() {
+        let mut out = alloc::vec![];
+        NumbersToWords::new(6047_u32).write_io(&mut out).unwrap();
+        assert_eq!(out, b"six thousand and forty seven");
+    }
+
+    #[test]
+    fn writes_to_buffers() {
+        let mut buffer = [0_u8; 64];
+        let actual = NumbersToWords::new(-6047_i16).write_to_buffer(&mut buffer);
+        assert_eq!(actual, Ok("minus six thousand and forty seven"));
+
+        let mut buffer = [0_u8; 10];
+        assert!(NumbersToWords::new(6047_u16).write_to_buffer(&mut buffer).is_err());
+    }
+
+    #[test]
+    fn writes_casing_and_terminators() {
+        let casings = [Casing::Lower, Casing::Sentence, Casing::Title, Casing::Upper];
+        casings.iter().for_each(|casing| {
+            let words = NumbersToWords::new(-1_042_i64).casing(*casing).terminator("only");
+            assert_eq!(written(&words), format!("{words}"));
+
+            let words = NumbersToWords::new(Ordinal(21_u8)).casing(*casing);
+            assert_eq!(written(&words), format!("{words}"));
+        });
+
+        let words = NumbersToWords::new(1_200_u32).casing(Casing::Upper).terminator("only");
+        assert_eq!(written(&words), "ONE THOUSAND TWO HUNDRED ONLY");
+
+        let words = NumbersToWords::new(101_u32).casing(Casing::Title).terminator("only");
+        assert_eq!(written(&words), "One Hundred and One Only");
+    }
+
+    fn options() -> impl Strategy<Value = RenderOptions> {
+        (any::<bool>(), any::<bool>(), any::<bool>(), any::<bool>()).prop_map(|(and, final_and, hyphenate, long_scale)| {
+            RenderOptions {
+                and,
+                and_placement: if final_and { AndPlacement::Final } else { AndPlacement::Remainder },
+                hyphenate,
+                long_scale,
+            }
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn matches_display(number in any::<u128>(), options in options(), ordinal in any::<bool>()) {
+            let words = NumbersToWords::new(number).options(options).with_ordinal(ordinal);
+            prop_assert_eq!(written(&words), format!("{words}"));
+        }
+
+        #[test]
+        fn matches_display_for_small_numbers(number in -100_000_i64..100_000, options in options()) {
+            let words = NumbersToWords::new(number).options(options);
+            prop_assert_eq!(written(&words), format!("{words}"));
+        }
+    }
+}
@@ -0,0 +1,384 @@
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader, BufWriter, Write},
+    process::ExitCode,
+};
+
+use crate::{exit_code, render};
+
+const USAGE: &str = "usage: numbers_to_words --batch [--format csv|tsv|lines] [--columns <name or number>,...] [--on-error skip|stop] [--no-header] [file]";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+    Csv,
+    Tsv,
+    // One value per line, written back out with its words after a tab
+    Lines,
+}
+
+impl Format {
+    fn delimiter(self) -> char {
+        match self {
+            Format::Csv => ',',
+            Format::Tsv | Format::Lines => '\t',
+        }
+    }
+
+    // None when a quoted CSV field is never closed
+    fn split(self, line: &str) -> Option<Vec<String>> {
+        match self {
+            Format::Csv => split_csv(line),
+            Format::Tsv => Some(line.split('\t').map(String::from).collect()),
+            Format::Lines => Some(vec![line.to_string()]),
+        }
+    }
+
+    fn quote(self, field: &str) -> String {
+        match self {
+            Format::Csv if field.contains([',', '"', '\n']) => format!("\"{}\"", field.replace('"', "\"\"")),
+            _ => field.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OnError {
+    // Leave the row out of the output and carry on
+    Skip,
+    // Stop at the first row that fails
+    Stop,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Options {
+    format: Format,
+    // Header names or column numbers counting from 1
+    columns: Vec<String>,
+    on_error: OnError,
+    header: bool,
+    path: Option<String>,
+}
+
+impl Options {
+    pub fn parse(args: &[String]) -> Result<Options, String> {
+        let mut options = Options {
+            format: Format::Csv,
+            columns: vec!["1".to_string()],
+            on_error: OnError::Stop,
+            header: true,
+            path: None,
+        };
+
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            let mut value = || args.next().ok_or_else(|| format!("{} needs a value", arg));
+            match arg.as_str() {
+                "--format" => {
+                    options.format = match value()?.as_str() {
+                        "csv" => Format::Csv,
+                        "tsv" => Format::Tsv,
+                        "lines" => Format::Lines,
+                        other => return Err(format!("unknown format \"{}\"", other)),
+                    }
+                }
+                "--columns" => options.columns = value()?.split(',').map(String::from).collect(),
+                "--on-error" => {
+                    options.on_error = match value()?.as_str() {
+                        "skip" => OnError::Skip,
+                        "stop" => OnError::Stop,
+                        other => return Err(format!("unknown error handling \"{}\"", other)),
+                    }
+                }
+                "--no-header" => options.header = false,
+                _ if arg.starts_with("--") => return Err(format!("unknown option {}", arg)),
+                _ if options.path.is_none() => options.path = Some(arg.clone()),
+                _ => return Err(format!("only one file can be converted at a time, got {}", arg)),
+            }
+        }
+
+        // Plain lines have neither a header nor more than one column
+        if options.format == Format::Lines {
+            options.header = false;
+        }
+
+        Ok(options)
+    }
+
+    // The index of each selected column, names being looked up in the header
+    fn resolve(&self, header: Option<&[String]>) -> Result<Vec<usize>, String> {
+        self.columns
+            .iter()
+            .map(|column| match (column.parse::<usize>(), header) {
+                (Ok(0), _) => Err("columns are numbered from 1".to_string()),
+                (Ok(number), _) => Ok(number - 1),
+                (Err(_), Some(header)) => header
+                    .iter()
+                    .position(|name| name.trim() == column)
+                    .ok_or_else(|| format!("no column named \"{}\"", column)),
+                (Err(_), None) => Err(format!("column \"{}\" can only be named when the input has a header", column)),
+            })
+            .collect()
+    }
+}
+
+// Quoted fields can hold the delimiter, with "" standing for a quote
+fn split_csv(line: &str) -> Option<Vec<String>> {
+    let mut fields = vec![];
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                chars.next();
+                field.push('"');
+            }
+            '"' if quoted => quoted = false,
+            '"' if field.is_empty() => quoted = true,
+            ',' if !quoted => fields.push(std::mem::take(&mut field)),
+            c => field.push(c),
+        }
+    }
+
+    if quoted {
+        return None;
+    }
+
+    fields.push(field);
+    Some(fields)
+}
+
+// Copies each row with the words for the selected columns added to the end, reporting
+// failed rows by line number. Returns the exit code of the first failure
+pub fn convert<R: BufRead, W: Write, E: Write>(input: R, output: &mut W, errors: &mut E, options: &Options) -> io::Result<u8> {
+    let delimiter = options.format.delimiter();
+    let mut columns = None;
+    let mut code = 0;
+
+    for (index, line) in input.lines().enumerate() {
+        let line = line?;
+        let line = line.strip_suffix('\r').unwrap_or(&line);
+        let number = index + 1;
+
+        let Some(fields) = options.format.split(line) else {
+            writeln!(errors, "line {}: a quoted field is never closed", number)?;
+            code = if code == 0 { 2 } else { code };
+            match options.on_error {
+                OnError::Skip => continue,
+                OnError::Stop => return Ok(code),
+            }
+        };
+
+        if index == 0 && options.header {
+            let resolved = match options.resolve(Some(&fields)) {
+                Ok(resolved) => resolved,
+                Err(err) => {
+                    writeln!(errors, "{}", err)?;
+                    return Ok(1);
+                }
+            };
+
+            write!(output, "{}", line)?;
+            for column in &resolved {
+                let name = fields.get(*column).map_or_else(|| format!("column {}", column + 1), |name| name.trim().to_string());
+                write!(output, "{}{}", delimiter, options.format.quote(&format!("{} in words", name)))?;
+            }
+            writeln!(output)?;
+
+            columns = Some(resolved);
+            continue;
+        }
+
+        let columns = match &columns {
+            Some(columns) => columns,
+            None => match options.resolve(None) {
+                Ok(resolved) => columns.insert(resolved),
+                Err(err) => {
+                    writeln!(errors, "{}", err)?;
+                    return Ok(1);
+                }
+            },
+        };
+
+        // Blank lines are kept so the output lines up with the input
+        if line.trim().is_empty() {
+            writeln!(output, "{}", line)?;
+            continue;
+        }
+
+        let mut words = vec![];
+        let mut failed = false;
+        for column in columns.iter().copied() {
+            let rendered = match fields.get(column) {
+                Some(value) if value.trim().is_empty() => Ok(String::new()),
+                Some(value) => render(value).map_err(|err| (err.to_string(), exit_code(&err))),
+                None => Err(("the row has no such column".to_string(), 2)),
+            };
+
+            match rendered {
+                Ok(rendered) => words.push(rendered),
+                Err((err, err_code)) => {
+                    writeln!(errors, "line {}, column {}: {}", number, column + 1, err)?;
+                    code = if code == 0 { err_code } else { code };
+                    failed = true;
+                }
+            }
+        }
+
+        if failed {
+            match options.on_error {
+                OnError::Skip => continue,
+                OnError::Stop => return Ok(code),
+            }
+        }
+
+        write!(output, "{}", line)?;
+        for word in &words {
+            write!(output, "{}{}", delimiter, options.format.quote(word))?;
+        }
+        writeln!(output)?;
+    }
+
+    Ok(code)
+}
+
+pub fn run(args: &[String]) -> ExitCode {
+    let options = match Options::parse(args) {
+        Ok(options) => options,
+        Err(err) => {
+            eprintln!("{}\n{}", err, USAGE);
+            return ExitCode::from(1);
+        }
+    };
+
+    let input: Box<dyn BufRead> = match &options.path {
+        Some(path) => match File::open(path) {
+            Ok(file) => Box::new(BufReader::new(file)),
+            Err(err) => {
+                eprintln!("cannot open {}: {}", path, err);
+                return ExitCode::from(1);
+            }
+        },
+        None => Box::new(io::stdin().lock()),
+    };
+
+    let mut output = BufWriter::new(io::stdout().lock());
+    let converted = convert(input, &mut output, &mut io::stderr(), &options).and_then(|code| {
+        output.flush()?;
+        Ok(code)
+    });
+
+    match converted {
+        Ok(code) => ExitCode::from(code),
+        Err(err) => {
+            eprintln!("{}", err);
+            ExitCode::from(1)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{convert, split_csv, Format, OnError, Options};
+
+    fn options(args: &[&str]) -> Options {
+        Options::parse(&args.iter().map(|arg| arg.to_string()).collect::<Vec<_>>()).unwrap()
+    }
+
+    fn converted(input: &str, args: &[&str]) -> (String, String, u8) {
+        let (mut output, mut errors) = (vec![], vec![]);
+        let code = convert(input.as_bytes(), &mut output, &mut errors, &options(args)).unwrap();
+        (String::from_utf8(output).unwrap(), String::from_utf8(errors).unwrap(), code)
+    }
+
+    #[test]
+    fn parses_options() {
+        let actual = options(&["--format", "tsv", "--columns", "amount,3", "--on-error", "skip", "--no-header", "in.tsv"]);
+        assert_eq!(actual, Options {
+            format: Format::Tsv,
+            columns: vec!["amount".to_string(), "3".to_string()],
+            on_error: OnError::Skip,
+            header: false,
+            path: Some("in.tsv".to_string()),
+        });
+
+        assert!(!options(&["--format", "lines"]).header);
+
+        let parse = |args: &[&str]| Options::parse(&args.iter().map(|arg| arg.to_string()).collect::<Vec<_>>());
+        assert_eq!(parse(&["--format", "xml"]), Err("unknown format \"xml\"".to_string()));
+        assert_eq!(parse(&["--columns"]), Err("--columns needs a value".to_string()));
+        assert_eq!(parse(&["--verbose"]), Err("unknown option --verbose".to_string()));
+    }
+
+    #[test]
+    fn splits_quoted_fields() {
+        assert_eq!(split_csv("a,\"b, c\",d"), Some(vec!["a".to_string(), "b, c".to_string(), "d".to_string()]));
+        assert_eq!(split_csv("\"say \"\"hi\"\"\",2"), Some(vec!["say \"hi\"".to_string(), "2".to_string()]));
+        assert_eq!(split_csv(",,"), Some(vec![String::new(), String::new(), String::new()]));
+        assert_eq!(split_csv("\"open,1"), None);
+    }
+
+    #[test]
+    fn converts_named_columns() {
+        let input = "id,amount,note\n1,42,\"rent, May\"\n2,1001,fee\r\n";
+        let (output, errors, code) = converted(input, &["--columns", "amount"]);
+
+        assert_eq!(output, "id,amount,note,amount in words\n1,42,\"rent, May\",forty two\n2,1001,fee,one thousand and one\n");
+        assert_eq!(errors, "");
+        assert_eq!(code, 0);
+    }
+
+    #[test]
+    fn converts_several_columns() {
+        let input = "3\t-7\n12\t0\n";
+        let (output, _, code) = converted(input, &["--format", "tsv", "--no-header", "--columns", "1,2"]);
+
+        assert_eq!(output, "3\t-7\tthree\tminus seven\n12\t0\ttwelve\tzero\n");
+        assert_eq!(code, 0);
+    }
+
+    #[test]
+    fn converts_lines() {
+        let (output, _, code) = converted("5\n\n-402\n", &["--format", "lines"]);
+
+        assert_eq!(output, "5\tfive\n\n-402\tminus four hundred and two\n");
+        assert_eq!(code, 0);
+    }
+
+    #[test]
+    fn skips_failed_rows() {
+        let input = "amount\n1\nabc\n2000000000000000000\n,\n3\n";
+        let (output, errors, code) = converted(input, &["--on-error", "skip"]);
+
+        assert_eq!(output, "amount,amount in words\n1,one\n,,\n3,three\n");
+        assert_eq!(errors, concat!(
+            "line 3, column 1: abc cannot be parsed, failed at position 0\n",
+            "line 4, column 1: 2000000000000000000 is not a valid number, it must be between -1000000000000000 and 1000000000000000\n",
+        ));
+        assert_eq!(code, 2);
+    }
+
+    #[test]
+    fn stops_at_failed_rows() {
+        let input = "amount,tax\n1,2\n9,x\n3,4\n";
+        let (output, errors, code) = converted(input, &["--columns", "amount,tax"]);
+
+        assert_eq!(output, "amount,tax,amount in words,tax in words\n1,2,one,two\n");
+        assert_eq!(errors, "line 3, column 2: x cannot be parsed, failed at position 0\n");
+        assert_eq!(code, 2);
+    }
+
+    #[test]
+    fn reports_bad_rows_and_columns() {
+        let (_, errors, code) = converted("a,b\n1,2\n", &["--columns", "c"]);
+        assert_eq!((errors.as_str(), code), ("no column named \"c\"\n", 1));
+
+        let (_, errors, code) = converted("1,2\n", &["--no-header", "--columns", "amount"]);
+        assert_eq!((errors.as_str(), code), ("column \"amount\" can only be named when the input has a header\n", 1));
+
+        let (output, errors, code) = converted("a,b\n1\n\"2,3\n4,5\n", &["--columns", "2", "--on-error", "skip"]);
+        assert_eq!(output, "a,b,b in words\n4,5,five\n");
+        assert_eq!(errors, "line 2, column 2: the row has no such column\nline 3: a quoted field is never closed\n");
+        assert_eq!(code, 2);
+    }
+}
@@ -1,21 +1,69 @@
-use tone_gen::{Sheet, SheetPlayer, SheetPlayerOption};
-
-fn main() {
-    let sheet = Sheet::from((10, 4));
-    let mut player = SheetPlayer::from(sheet);
-
-    loop {
-        player.play();
-
-        match player.read_input() {
-            Ok(option) => match option {
-                SheetPlayerOption::Play => (),
-                SheetPlayerOption::Exit => {
-                    println!("See you next time!");
-                    break;
-                }
-            }
-            Err(err) =>   println!("{}", err)
-        }
+use std::{
+    env,
+    io::{self, BufRead},
+    process::ExitCode,
+};
+
+use number_renderer::{InputError, NumbersToWords, Validator};
+
+mod batch;
+mod repl;
+
+const LOWER_BOUND: i64 = -1_000_000_000_000_000;
+const UPPER_BOUND: i64 = 1_000_000_000_000_000;
+
+fn exit_code(err: &InputError<i64>) -> u8 {
+    match err {
+        InputError::ParseError { .. } => 2,
+        InputError::ValidationError { .. } => 3,
+    }
+}
+
+fn render(input: &str) -> Result<String, InputError<i64>> {
+    NumbersToWords::<i64>::validate(input.trim(), LOWER_BOUND, UPPER_BOUND)
+        .map(|words| words.to_string())
+}
+
+fn main() -> ExitCode {
+    let args = env::args().skip(1).collect::<Vec<String>>();
+    if args.first().is_some_and(|arg| arg == "--batch") {
+        return batch::run(&args[1..]);
+    }
+
+    if args.first().is_some_and(|arg| arg == "--repl") {
+        return repl::run(&args[1..]);
     }
+
+    let inputs = if args.is_empty() {
+        io::stdin()
+            .lock()
+            .lines()
+            .map_while(Result::ok)
+            .filter(|line| !line.trim().is_empty())
+            .collect::<Vec<String>>()
+    } else {
+        args
+    };
+
+    if inputs.is_empty() {
+        eprintln!("usage: numbers_to_words <number>... (or pipe numbers on stdin, one per line)");
+        eprintln!("       numbers_to_words --batch [file] to add words to the columns of a CSV file");
+        eprintln!("       numbers_to_words --repl to check numbers one at a time");
+        return ExitCode::from(1);
+    }
+
+    inputs
+        .iter()
+        .map(|input| match render(input) {
+            Ok(words) => {
+                println!("{}", words);
+                0
+            }
+            Err(err) => {
+                eprintln!("{}", err);
+                exit_code(&err)
+            }
+        })
+        .fold(0, |first, code| if first == 0 { code } else { first })
+        .into()
 }
@@ -0,0 +1,320 @@
+use std::{
+    env,
+    fs::{self, File, OpenOptions},
+    io::{self, BufRead, Write},
+    path::PathBuf,
+    process::ExitCode,
+};
+
+use number_renderer::{French, German, Language, NumbersToWords, Polish, RenderOptions, Russian, Spanish, Validator};
+
+use crate::{LOWER_BOUND, UPPER_BOUND};
+
+const USAGE: &str = "usage: numbers_to_words --repl [--history <file> | --no-history]";
+
+const HELP: &str = "\
+enter a number to read it out, or one of
+  :style default|british|american   how English numbers are joined
+  :language <name>                   english, french, german, spanish, russian or polish
+  :ordinal, :cardinal                read positions, \"first\", or counts, \"one\"
+  :show                              the current settings
+  :history                           the numbers and commands entered so far
+  :help, :quit";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Choice {
+    English,
+    French,
+    German,
+    Spanish,
+    Russian,
+    Polish,
+}
+
+impl Choice {
+    const ALL: [(&'static str, Choice); 6] = [
+        ("english", Choice::English),
+        ("french", Choice::French),
+        ("german", Choice::German),
+        ("spanish", Choice::Spanish),
+        ("russian", Choice::Russian),
+        ("polish", Choice::Polish),
+    ];
+
+    fn name(self) -> &'static str {
+        Choice::ALL.iter().find(|(_, choice)| *choice == self).map_or("", |(name, _)| name)
+    }
+}
+
+// The English reading styles, by the name :style takes
+fn style(name: &str) -> Option<(&'static str, RenderOptions)> {
+    match name {
+        "default" => Some(("default", RenderOptions::default())),
+        "british" => Some(("british", RenderOptions::BRITISH)),
+        "american" => Some(("american", RenderOptions::AMERICAN)),
+        _ => None,
+    }
+}
+
+fn in_language<L: Language>(words: NumbersToWords<i64>, language: L) -> String
+where
+    NumbersToWords<i64, L>: std::fmt::Display,
+{
+    words.language(language).to_string()
+}
+
+// The settings a session reads numbers with, and what has been entered so far
+pub struct Session {
+    style: &'static str,
+    options: RenderOptions,
+    language: Choice,
+    ordinal: bool,
+    history: Vec<String>,
+}
+
+impl Session {
+    pub fn new(history: Vec<String>) -> Self {
+        Session {
+            style: "default",
+            options: RenderOptions::default(),
+            language: Choice::English,
+            ordinal: false,
+            history,
+        }
+    }
+
+    fn render(&self, input: &str) -> Result<String, String> {
+        let words = NumbersToWords::<i64>::validate(input, LOWER_BOUND, UPPER_BOUND)
+            .map_err(|err| err.to_string())?
+            .options(self.options);
+
+        if self.ordinal {
+            return match self.language {
+                Choice::English => Ok(words.ordinal().to_string()),
+                _ => Err(format!("ordinals can only be read in English, not {}", self.language.name())),
+            };
+        }
+
+        Ok(match self.language {
+            Choice::English => words.to_string(),
+            Choice::French => in_language(words, French),
+            Choice::German => in_language(words, German),
+            Choice::Spanish => in_language(words, Spanish),
+            Choice::Russian => in_language(words, Russian),
+            Choice::Polish => in_language(words, Polish),
+        })
+    }
+
+    fn show(&self) -> String {
+        let kind = if self.ordinal { "ordinal" } else { "cardinal" };
+        format!("style {}, language {}, {}", self.style, self.language.name(), kind)
+    }
+
+    // What to print for a line, or None once the session is over. Errors are
+    // returned as text too, so a bad line never ends the session
+    pub fn respond(&mut self, line: &str) -> Option<String> {
+        let line = line.trim();
+        self.history.push(line.to_string());
+
+        let Some(command) = line.strip_prefix(':') else {
+            return Some(self.render(line).unwrap_or_else(|err| format!("error: {}", err)));
+        };
+
+        let (name, argument) = command.split_once(' ').unwrap_or((command, ""));
+        let argument = argument.trim().to_lowercase();
+        let reply = match name {
+            "quit" | "q" | "exit" => return None,
+            "help" | "h" => HELP.to_string(),
+            "show" => self.show(),
+            "history" => self.history.join("\n"),
+            "ordinal" => {
+                self.ordinal = true;
+                self.show()
+            }
+            "cardinal" => {
+                self.ordinal = false;
+                self.show()
+            }
+            "style" => match style(&argument) {
+                Some((name, options)) => {
+                    self.style = name;
+                    self.options = options;
+                    self.show()
+                }
+                None => format!("error: unknown style \"{}\", try british, american or default", argument),
+            },
+            "language" | "lang" => match Choice::ALL.iter().find(|(language, _)| *language == argument) {
+                Some((_, choice)) => {
+                    self.language = *choice;
+                    self.show()
+                }
+                None => format!("error: unknown language \"{}\", see :help", argument),
+            },
+            _ => format!("error: unknown command :{}, see :help", name),
+        };
+
+        Some(reply)
+    }
+}
+
+fn history_path(args: &[String]) -> Result<Option<PathBuf>, String> {
+    match args {
+        [] => Ok(env::var_os("HOME").map(|home| PathBuf::from(home).join(".numbers_to_words_history"))),
+        [flag] if flag == "--no-history" => Ok(None),
+        [flag, path] if flag == "--history" => Ok(Some(PathBuf::from(path))),
+        _ => Err(USAGE.to_string()),
+    }
+}
+
+pub fn run(args: &[String]) -> ExitCode {
+    let path = match history_path(args) {
+        Ok(path) => path,
+        Err(err) => {
+            eprintln!("{}", err);
+            return ExitCode::from(1);
+        }
+    };
+
+    // Earlier sessions are read back so :history covers them, and each line is saved as it
+    // is entered, so nothing is lost if the session is killed
+    let earlier = path
+        .as_ref()
+        .and_then(|path| fs::read_to_string(path).ok())
+        .map(|history| history.lines().map(String::from).collect())
+        .unwrap_or_default();
+    let mut history: Option<File> = path.as_ref().and_then(|path| {
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .map_err(|err| eprintln!("history will not be saved, cannot open {}: {}", path.display(), err))
+            .ok()
+    });
+
+    let mut session = Session::new(earlier);
+    let mut stdin = io::stdin().lock();
+    let mut line = String::new();
+    loop {
+        print!("> ");
+        if io::stdout().flush().is_err() {
+            return ExitCode::from(1);
+        }
+
+        line.clear();
+        match stdin.read_line(&mut line) {
+            Ok(0) => break,
+            Ok(_) => {}
+            Err(err) => {
+                eprintln!("{}", err);
+                return ExitCode::from(1);
+            }
+        }
+
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        if let Some(file) = history.as_mut() {
+            if writeln!(file, "{}", line.trim()).is_err() {
+                eprintln!("history will not be saved from here on");
+                history = None;
+            }
+        }
+
+        match session.respond(&line) {
+            Some(reply) => println!("{}", reply),
+            None => break,
+        }
+    }
+
+    ExitCode::SUCCESS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{history_path, Session};
+
+    fn replies(lines: &[&str]) -> Vec<Option<String>> {
+        let mut session = Session::new(vec![]);
+        lines.iter().map(|line| session.respond(line)).collect()
+    }
+
+    fn reply(text: &str) -> Option<String> {
+        Some(text.to_string())
+    }
+
+    #[test]
+    fn reads_numbers() {
+        assert_eq!(replies(&["42", " -7 "]), vec![reply("forty two"), reply("minus seven")]);
+    }
+
+    #[test]
+    fn shows_errors_inline() {
+        let actual = replies(&["12x", "2000000000000000", "5"]);
+
+        assert_eq!(actual, vec![
+            reply("error: 12x cannot be parsed, failed at position 2"),
+            reply("error: 2000000000000000 is not a valid number, it must be between -1000000000000000 and 1000000000000000"),
+            reply("five"),
+        ]);
+    }
+
+    #[test]
+    fn switches_settings() {
+        let actual = replies(&[":style american", "121", ":ordinal", "121", ":cardinal", ":style default", "121"]);
+
+        assert_eq!(actual, vec![
+            reply("style american, language english, cardinal"),
+            reply("one hundred twenty-one"),
+            reply("style american, language english, ordinal"),
+            reply("one hundred twenty-first"),
+            reply("style american, language english, cardinal"),
+            reply("style default, language english, cardinal"),
+            reply("one hundred and twenty one"),
+        ]);
+    }
+
+    #[test]
+    fn switches_languages() {
+        let actual = replies(&[":language French", "80", ":lang russian", "2000", ":ordinal", "3"]);
+
+        assert_eq!(actual, vec![
+            reply("style default, language french, cardinal"),
+            reply("quatre-vingts"),
+            reply("style default, language russian, cardinal"),
+            reply("две тысячи"),
+            reply("style default, language russian, ordinal"),
+            reply("error: ordinals can only be read in English, not russian"),
+        ]);
+    }
+
+    #[test]
+    fn rejects_unknown_commands() {
+        let actual = replies(&[":style loud", ":language klingon", ":frobnicate"]);
+
+        assert_eq!(actual, vec![
+            reply("error: unknown style \"loud\", try british, american or default"),
+            reply("error: unknown language \"klingon\", see :help"),
+            reply("error: unknown command :frobnicate, see :help"),
+        ]);
+    }
+
+    #[test]
+    fn keeps_history() {
+        let mut session = Session::new(vec!["1".to_string()]);
+        session.respond("2");
+        session.respond(":ordinal");
+
+        assert_eq!(session.respond(":history"), reply("1\n2\n:ordinal\n:history"));
+        assert_eq!(session.respond(":quit"), None);
+    }
+
+    #[test]
+    fn parses_history_options() {
+        let args = |args: &[&str]| history_path(&args.iter().map(|arg| arg.to_string()).collect::<Vec<_>>());
+
+        assert_eq!(args(&["--no-history"]), Ok(None));
+        assert_eq!(args(&["--history", "h.txt"]), Ok(Some("h.txt".into())));
+        assert!(args(&["--history"]).is_err());
+    }
+}